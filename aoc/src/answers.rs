@@ -0,0 +1,299 @@
+//! The expected answers for every registered input, read from `answers.toml`.
+//!
+//! The file is keyed by day and then by a name for the input:
+//!
+//! ```toml
+//! [1.example]
+//! path = "day_one/src/example_input.txt"
+//! part_one = 7
+//! part_two = 5
+//! ```
+//!
+//! Paths are relative to the answers file.  A part whose answer is not known is left out.
+use crate::find_day;
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{Answer, DynSolution, Part};
+use serde::Deserialize;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ExpectedAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl ExpectedAnswer {
+    /// Trailing whitespace is ignored so that multi-line answers may end with a newline in the file
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            ExpectedAnswer::Number(expected) => answer.to_string() == expected.to_string(),
+            ExpectedAnswer::Text(expected) => answer.to_string().trim_end() == expected.trim_end(),
+        }
+    }
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedAnswer::Number(value) => write!(f, "{}", value),
+            ExpectedAnswer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegisteredInput {
+    pub path: PathBuf,
+    pub part_one: Option<ExpectedAnswer>,
+    pub part_two: Option<ExpectedAnswer>,
+}
+
+impl RegisteredInput {
+    pub fn expected(&self, part: Part) -> Option<&ExpectedAnswer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Answers {
+    /// The paths of inputs are relative to this
+    root: PathBuf,
+    days: BTreeMap<u8, BTreeMap<String, RegisteredInput>>,
+}
+
+impl Answers {
+    pub fn load<P>(answers_path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let answers_path = answers_path.as_ref();
+        let contents = std::fs::read_to_string(answers_path)
+            .context(format!("Failed to read {}", answers_path.display()))?;
+        let root = answers_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        Answers::parse(&contents, root)
+            .context(format!("Failed to parse {}", answers_path.display()))
+    }
+
+    /// Paths in contents are relative to root
+    pub fn parse<P>(contents: &str, root: P) -> Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let by_day: BTreeMap<String, BTreeMap<String, RegisteredInput>> = toml::from_str(contents)?;
+        let days = by_day
+            .into_iter()
+            .map(|(day, inputs)| match day.parse::<u8>() {
+                Ok(day_number) => Ok((day_number, inputs)),
+                Err(_) => Err(anyhow!("Days must be numbered.  Got {:?}", day)),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Answers {
+            root: root.into(),
+            days,
+        })
+    }
+
+    /// Every registered (day, input name, input) in order of day and then name
+    pub fn inputs(&self) -> impl Iterator<Item = (u8, &str, &RegisteredInput)> {
+        self.days.iter().flat_map(|(day, inputs)| {
+            inputs
+                .iter()
+                .map(move |(name, input)| (*day, name.as_str(), input))
+        })
+    }
+
+    pub fn path_of(&self, input: &RegisteredInput) -> PathBuf {
+        self.root.join(&input.path)
+    }
+
+    /// Run every day against each of its registered inputs.  Every part with an expected answer
+    /// gets a check
+    pub fn check(&self) -> Vec<Check> {
+        let mut checks = Vec::new();
+        for (day, name, input) in self.inputs() {
+            let parsed = self.parse_input(day, input);
+
+            for part in [Part::One, Part::Two] {
+                let expected = match input.expected(part) {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+
+                let answer = match &parsed {
+                    Ok((solution, parsed)) => solution.run(part, parsed.as_ref()),
+                    Err(error) => Err(anyhow!("{:#}", error)),
+                };
+                let outcome = match answer {
+                    Ok(actual) if expected.matches(&actual) => Outcome::Correct,
+                    Ok(actual) => Outcome::Wrong {
+                        expected: expected.clone(),
+                        actual,
+                    },
+                    Err(error) => Outcome::Failed(format!("{:#}", error)),
+                };
+
+                checks.push(Check {
+                    day,
+                    input: name.to_string(),
+                    part,
+                    outcome,
+                });
+            }
+        }
+
+        checks
+    }
+
+    fn parse_input(
+        &self,
+        day: u8,
+        input: &RegisteredInput,
+    ) -> Result<(&'static dyn DynSolution, Box<dyn Any>)> {
+        let solution = find_day(day).ok_or_else(|| anyhow!("Day {} has not been solved", day))?;
+        let input_path = self.path_of(input);
+        let contents = std::fs::read_to_string(&input_path)
+            .context(format!("Failed to read {}", input_path.display()))?;
+        let parsed = solution
+            .parse(&contents)
+            .context(format!("Failed to parse {}", input_path.display()))?;
+
+        Ok((solution, parsed))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        expected: ExpectedAnswer,
+        actual: Answer,
+    },
+    /// The input could not be read or parsed, or the part returned an error
+    Failed(String),
+}
+
+/// The result of running one part of one day against one registered input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn is_correct(&self) -> bool {
+        self.outcome == Outcome::Correct
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {} ({}): ", self.day, self.part, self.input)?;
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual } => {
+                write!(f, "expected {}, got {}", expected, actual)
+            }
+            Outcome::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, ExpectedAnswer, Outcome};
+    use aoc_common::{Answer, Part};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+            [3.example]
+            path = "day_three/src/example_input.txt"
+            part_one = 198
+
+            [13.mine]
+            path = "day_thirteen/src/input.txt"
+            part_two = """
+            #..#
+            """
+            "#,
+            "..",
+        )
+        .unwrap();
+
+        let inputs: Vec<_> = answers.inputs().collect();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!((inputs[0].0, inputs[0].1), (3, "example"));
+        assert_eq!(
+            inputs[0].2.expected(Part::One),
+            Some(&ExpectedAnswer::Number(198))
+        );
+        assert_eq!(inputs[0].2.expected(Part::Two), None);
+        assert_eq!(
+            answers.path_of(inputs[0].2),
+            PathBuf::from("../day_three/src/example_input.txt")
+        );
+
+        assert!(Answers::parse("[three.example]\npath = \"a\"", "").is_err());
+        assert!(Answers::parse("[3.example]\npart_one = 1", "").is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(ExpectedAnswer::Number(-25).matches(&Answer::Signed(-25)));
+        assert!(ExpectedAnswer::Number(7).matches(&Answer::Unsigned(7)));
+        assert!(!ExpectedAnswer::Number(7).matches(&Answer::Unsigned(8)));
+        assert!(ExpectedAnswer::Text("#.\n.#\n".to_string())
+            .matches(&Answer::Text("#.\n.#".to_string())));
+    }
+
+    #[test]
+    fn test_check_reports_mismatches() {
+        let answers = Answers::parse(
+            r#"
+            [3.example]
+            path = "day_three/src/example_input.txt"
+            part_one = 198
+            part_two = 1
+
+            [3.missing]
+            path = "day_three/src/missing.txt"
+            part_one = 198
+            "#,
+            "..",
+        )
+        .unwrap();
+
+        let checks = answers.check();
+        assert_eq!(checks.len(), 3);
+        assert!(checks[0].is_correct());
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Wrong {
+                expected: ExpectedAnswer::Number(1),
+                actual: Answer::Unsigned(230)
+            }
+        );
+        assert_eq!(
+            checks[1].to_string(),
+            "day 3 part 2 (example): expected 1, got 230"
+        );
+        assert!(matches!(checks[2].outcome, Outcome::Failed(_)));
+    }
+}
@@ -0,0 +1,304 @@
+//! Time parsing and both parts of a day, separately, over many iterations.
+//!
+//! Only `std::time::Instant` is used so the numbers are rough, but they are consistent enough
+//! from run to run to spot a regression.
+use anyhow::{anyhow, Result};
+use aoc_common::{DynSolution, Part};
+use serde::Serialize;
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Runs that are timed
+    pub iterations: usize,
+    /// Runs before timing starts so caches and the allocator have settled
+    pub warm_up: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 10,
+            warm_up: 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::PartOne => write!(f, "part_one"),
+            Stage::PartTwo => write!(f, "part_two"),
+        }
+    }
+}
+
+/// Summary of the samples taken for one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /// None if there are no samples.  The median of an even number of samples is the mean of the
+    /// middle two
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (*samples.get(middle.checked_sub(1)?)? + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Timings {
+            median,
+            min: *samples.first()?,
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// The timings of one stage of one day on one input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub input: String,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Measurement {
+    fn new(day: u8, input: &str, stage: Stage, iterations: usize, timings: Timings) -> Self {
+        Measurement {
+            day,
+            input: input.to_string(),
+            stage,
+            iterations,
+            median_ns: nanoseconds(timings.median),
+            min_ns: nanoseconds(timings.min),
+            max_ns: nanoseconds(timings.max),
+        }
+    }
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Time parse, part one and part two of solution on input.  input_name is only used to label the
+/// measurements
+pub fn bench(
+    solution: &dyn DynSolution,
+    input_name: &str,
+    input: &str,
+    options: BenchOptions,
+) -> Result<Vec<Measurement>> {
+    if options.iterations == 0 {
+        return Err(anyhow!("At least one iteration is needed to time anything"));
+    }
+
+    let parse = time(options, || solution.parse(input).map(drop))?;
+    let parsed = solution.parse(input)?;
+    let part_one = time(options, || {
+        solution.run(Part::One, parsed.as_ref()).map(drop)
+    })?;
+    let part_two = time(options, || {
+        solution.run(Part::Two, parsed.as_ref()).map(drop)
+    })?;
+
+    let day = solution.day();
+    let measure =
+        |stage, timings| Measurement::new(day, input_name, stage, options.iterations, timings);
+    Ok(vec![
+        measure(Stage::Parse, parse),
+        measure(Stage::PartOne, part_one),
+        measure(Stage::PartTwo, part_two),
+    ])
+}
+
+fn time<F>(options: BenchOptions, mut run: F) -> Result<Timings>
+where
+    F: FnMut() -> Result<()>,
+{
+    for _ in 0..options.warm_up {
+        run()?;
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        run()?;
+        samples.push(start.elapsed());
+    }
+
+    Timings::from_samples(samples).ok_or_else(|| anyhow!("No samples were taken"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Aligned columns for reading in a terminal
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            other => Err(anyhow!(
+                "format must be table, csv or json.  Got {:?}",
+                other
+            )),
+        }
+    }
+}
+
+pub fn write_measurements<W>(
+    mut writer: W,
+    format: Format,
+    measurements: &[Measurement],
+) -> Result<()>
+where
+    W: Write,
+{
+    match format {
+        Format::Table => {
+            writeln!(
+                writer,
+                "{:>3}  {:<12}  {:<8}  {:>12}  {:>12}  {:>12}",
+                "day", "input", "stage", "median", "min", "max"
+            )?;
+            for measurement in measurements {
+                writeln!(
+                    writer,
+                    "{:>3}  {:<12}  {:<8}  {:>12}  {:>12}  {:>12}",
+                    measurement.day,
+                    measurement.input,
+                    measurement.stage.to_string(),
+                    format_nanoseconds(measurement.median_ns),
+                    format_nanoseconds(measurement.min_ns),
+                    format_nanoseconds(measurement.max_ns)
+                )?;
+            }
+        }
+        Format::Csv => {
+            writeln!(writer, "day,input,stage,iterations,median_ns,min_ns,max_ns")?;
+            for measurement in measurements {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{}",
+                    measurement.day,
+                    measurement.input,
+                    measurement.stage,
+                    measurement.iterations,
+                    measurement.median_ns,
+                    measurement.min_ns,
+                    measurement.max_ns
+                )?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, measurements)?;
+            writeln!(writer)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Formatted before padding because Duration's Debug output does not honour widths
+fn format_nanoseconds(nanoseconds: u64) -> String {
+    format!("{:?}", Duration::from_nanos(nanoseconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{bench, write_measurements, BenchOptions, Format, Stage, Timings};
+    use crate::find_day;
+    use std::time::Duration;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = Timings::from_samples(millis(&[5, 1, 3])).unwrap();
+        assert_eq!(timings.median, Duration::from_millis(3));
+        assert_eq!(timings.min, Duration::from_millis(1));
+        assert_eq!(timings.max, Duration::from_millis(5));
+
+        let timings = Timings::from_samples(millis(&[4, 1, 2, 8])).unwrap();
+        assert_eq!(timings.median, Duration::from_millis(3));
+
+        assert_eq!(Timings::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let day_seven = find_day(7).unwrap();
+        let options = BenchOptions {
+            iterations: 3,
+            warm_up: 1,
+        };
+        let measurements = bench(day_seven, "example", "16,1,2,0,4,2,7,1,2,14", options).unwrap();
+
+        let stages: Vec<_> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::PartOne, Stage::PartTwo]);
+        assert!(measurements
+            .iter()
+            .all(|m| m.min_ns <= m.median_ns && m.median_ns <= m.max_ns));
+
+        assert!(bench(day_seven, "bad", "1,x", options).is_err());
+        let no_iterations = BenchOptions {
+            iterations: 0,
+            warm_up: 0,
+        };
+        assert!(bench(day_seven, "example", "1,2", no_iterations).is_err());
+    }
+
+    #[test]
+    fn test_write_measurements() {
+        let day_seven = find_day(7).unwrap();
+        let measurements = bench(day_seven, "example", "1,2", BenchOptions::default()).unwrap();
+
+        let mut csv = Vec::new();
+        write_measurements(&mut csv, Format::Csv, &measurements).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "day,input,stage,iterations,median_ns,min_ns,max_ns"
+        );
+        assert!(lines[2].starts_with("7,example,part_one,10,"));
+
+        let mut json = Vec::new();
+        write_measurements(&mut json, Format::Json, &measurements).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json[1]["stage"], "part_one");
+        assert_eq!(json[1]["day"], 7);
+    }
+}
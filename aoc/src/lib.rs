@@ -0,0 +1,180 @@
+pub mod answers;
+pub mod bench;
+pub mod logger;
+pub mod record;
+pub mod run_all;
+
+use anyhow::Result;
+use aoc_common::{DynSolution, Rng};
+use aoc_image::Frames;
+
+/// Every solved day.  Adding a day to the runner only requires adding it here
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day_one::DayOne,
+    &day_two::DayTwo,
+    &day_three::DayThree,
+    &day_four::DayFour,
+    &day_five::DayFive,
+    &day_six::DaySix,
+    &day_seven::DaySeven,
+    &day_eight::DayEight,
+    &day_nine::DayNine,
+    &day_ten::DayTen,
+    &day_eleven::DayEleven,
+    &day_twelve::DayTwelve,
+    &day_thirteen::DayThirteen,
+    &day_fourteen::DayFourteen,
+    &day_fifteen::DayFifteen,
+    &day_sixteen::DaySixteen,
+    &day_seventeen::DaySeventeen,
+    &day_eighteen::DayEighteen,
+    &day_nineteen::DayNineteen,
+    &day_twenty::DayTwenty,
+    &day_twenty_one::DayTwentyOne,
+    &day_twenty_two::DayTwentyTwo,
+    &day_twenty_three::DayTwentyThree,
+    &day_twenty_four::DayTwentyFour,
+    &day_twenty_five::DayTwentyFive,
+];
+
+pub fn find_day(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|solution| solution.day() == day)
+}
+
+/// Makes a random input from a seeded [`Rng`] and a scale, where a scale of 1 is about the size of
+/// a real input
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The input generator of every solved day
+pub static GENERATORS: &[(u8, Generator)] = &[
+    (1, day_one::generator::generate_scaled),
+    (2, day_two::generator::generate_scaled),
+    (3, day_three::generator::generate_scaled),
+    (4, day_four::generator::generate_scaled),
+    (5, day_five::generator::generate_scaled),
+    (6, day_six::generator::generate_scaled),
+    (7, day_seven::generator::generate_scaled),
+    (8, day_eight::generator::generate_scaled),
+    (9, day_nine::generator::generate_scaled),
+    (10, day_ten::generator::generate_scaled),
+    (11, day_eleven::generator::generate_scaled),
+    (12, day_twelve::generator::generate_scaled),
+    (13, day_thirteen::generator::generate_scaled),
+    (14, day_fourteen::generator::generate_scaled),
+    (15, day_fifteen::generator::generate_scaled),
+    (16, day_sixteen::generator::generate_scaled),
+    (17, day_seventeen::generator::generate_scaled),
+    (18, day_eighteen::generator::generate_scaled),
+    (19, day_nineteen::generator::generate_scaled),
+    (20, day_twenty::generator::generate_scaled),
+    (21, day_twenty_one::generator::generate_scaled),
+    (22, day_twenty_two::generator::generate_scaled),
+    (23, day_twenty_three::generator::generate_scaled),
+    (24, day_twenty_four::generator::generate_scaled),
+    (25, day_twenty_five::generator::generate_scaled),
+];
+
+pub fn find_generator(day: u8) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(generator_day, _)| *generator_day == day)
+        .map(|(_, generator)| *generator)
+}
+
+/// Parses an input and writes an image of every step of solving it
+pub type Visualizer = fn(&str, &mut Frames) -> Result<()>;
+
+/// The days whose state is a grid worth looking at
+pub static VISUALIZERS: &[(u8, Visualizer)] = &[
+    (9, day_nine::visualize::visualize),
+    (11, day_eleven::visualize::visualize),
+    (13, day_thirteen::visualize::visualize),
+    (15, day_fifteen::visualize::visualize),
+];
+
+pub fn find_visualizer(day: u8) -> Option<Visualizer> {
+    VISUALIZERS
+        .iter()
+        .find(|(visualizer_day, _)| *visualizer_day == day)
+        .map(|(_, visualizer)| *visualizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_day, find_generator, DAYS};
+    use aoc_common::{Answer, Part, Rng};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_days_are_unique() {
+        let days: HashSet<u8> = DAYS.iter().map(|solution| solution.day()).collect();
+        assert_eq!(days.len(), DAYS.len());
+    }
+
+    #[test]
+    fn test_find_day() {
+        assert_eq!(find_day(16).map(|solution| solution.day()), Some(16));
+        assert!(find_day(26).is_none());
+    }
+
+    #[test]
+    fn test_every_day_has_a_generator() {
+        for solution in DAYS {
+            let generator = find_generator(solution.day()).expect("every day has a generator");
+            let input = generator(&mut Rng::new(0), 1);
+            assert!(
+                solution.parse(&input).is_ok(),
+                "day {} generated an input it cannot parse",
+                solution.day()
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_examples() {
+        let day_one = find_day(1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        assert_eq!(
+            day_one.solve(Part::One, input).unwrap(),
+            Answer::Unsigned(7)
+        );
+        assert_eq!(
+            day_one.solve(Part::Two, input).unwrap(),
+            Answer::Unsigned(5)
+        );
+
+        let day_two = find_day(2).unwrap();
+        let input = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+        assert_eq!(
+            day_two.solve(Part::One, input).unwrap(),
+            Answer::Signed(150)
+        );
+        assert_eq!(
+            day_two.solve(Part::Two, input).unwrap(),
+            Answer::Signed(900)
+        );
+
+        let day_seven = find_day(7).unwrap();
+        let input = "16,1,2,0,4,2,7,1,2,14\n";
+        assert_eq!(
+            day_seven.solve(Part::One, input).unwrap(),
+            Answer::Signed(37)
+        );
+        assert_eq!(
+            day_seven.solve(Part::Two, input).unwrap(),
+            Answer::Signed(168)
+        );
+
+        let day_sixteen = find_day(16).unwrap();
+        assert_eq!(
+            day_sixteen.solve(Part::One, "8A004A801A8002F478").unwrap(),
+            Answer::Unsigned(16)
+        );
+        assert_eq!(
+            day_sixteen
+                .solve(Part::Two, "9C0141080250320F1802104A08")
+                .unwrap(),
+            Answer::Unsigned(1)
+        );
+    }
+}
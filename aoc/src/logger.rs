@@ -0,0 +1,123 @@
+//! Print the solvers' traces to stderr.
+//!
+//! Days log through the `log` facade and their records carry the crate name as the target, so a
+//! filter like `warn,day_thirteen=debug` shows day thirteen's folds and only warnings from
+//! everything else.  Nothing is logged unless a logger is installed.
+use anyhow::{anyhow, Result};
+use log::{LevelFilter, Log, Metadata, Record};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logger {
+    /// The level of every target that is not listed in targets
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Logger {
+    /// The level of the most specific listed target that target is, or is a module of
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(name, _)| {
+                target == name
+                    || target
+                        .strip_prefix(name.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// Install as the global logger.  Fails if a logger is already installed
+    pub fn init(self) -> Result<()> {
+        let max_level = self
+            .targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max);
+        log::set_logger(Box::leak(Box::new(self)))
+            .map_err(|_| anyhow!("A logger is already installed"))?;
+        log::set_max_level(max_level);
+
+        Ok(())
+    }
+}
+
+/// A comma separated list of a default level and target=level pairs, e.g. `info,day_nine=trace`.
+/// A target on its own logs everything from it
+impl FromStr for Logger {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut logger = Logger {
+            default: LevelFilter::Off,
+            targets: Vec::new(),
+        };
+
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let level = level
+                        .parse()
+                        .map_err(|_| anyhow!("Unknown log level {:?}", level))?;
+                    logger.targets.push((target.to_string(), level));
+                }
+                None => match directive.parse() {
+                    Ok(level) => logger.default = level,
+                    Err(_) => logger
+                        .targets
+                        .push((directive.to_string(), LevelFilter::Trace)),
+                },
+            }
+        }
+
+        Ok(logger)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logger::Logger;
+    use log::LevelFilter;
+
+    #[test]
+    fn test_parse_logger() {
+        let logger: Logger = "debug".parse().unwrap();
+        assert_eq!(logger.level_for("day_nine"), LevelFilter::Debug);
+
+        let logger: Logger = "warn, day_thirteen=trace,day_eleven".parse().unwrap();
+        assert_eq!(logger.level_for("day_thirteen"), LevelFilter::Trace);
+        assert_eq!(logger.level_for("day_eleven"), LevelFilter::Trace);
+        assert_eq!(logger.level_for("day_nine"), LevelFilter::Warn);
+
+        assert!("day_nine=loud".parse::<Logger>().is_err());
+    }
+
+    #[test]
+    fn test_level_for_modules() {
+        let logger: Logger = "day_sixteen=info,day_sixteen::generator=off"
+            .parse()
+            .unwrap();
+        assert_eq!(logger.level_for("day_sixteen"), LevelFilter::Info);
+        assert_eq!(logger.level_for("day_sixteen::parser"), LevelFilter::Info);
+        assert_eq!(logger.level_for("day_sixteen::generator"), LevelFilter::Off);
+        // A target that only shares a prefix with day_one is not part of it
+        let logger: Logger = "day_one=debug".parse().unwrap();
+        assert_eq!(logger.level_for("day_one_extra"), LevelFilter::Off);
+    }
+}
@@ -0,0 +1,769 @@
+use anyhow::{anyhow, Context, Result};
+use aoc::answers::Answers;
+use aoc::bench::{bench, write_measurements, BenchOptions, Format};
+use aoc::logger::Logger;
+use aoc::record::RunRecord;
+use aoc::run_all::{run_all, write_summary, Task};
+use aoc_common::{Part, Rng};
+use aoc_image::Frames;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+const USAGE: &str = "usage: aoc --day N --part 1|2 --input PATH [--format text|json] [--log FILTER]
+       aoc check [ANSWERS]
+       aoc bench [--day N] [--input PATH] [--answers ANSWERS] [--iterations N] [--warm-up N]
+                 [--format table|csv|json]
+       aoc generate --day N [--seed SEED] [--scale SCALE]
+       aoc all [--answers ANSWERS] [--threads N]
+       aoc visualize --day N --input PATH --output DIRECTORY [--scale SCALE]
+
+A PATH of - reads the input from stdin.  --format json prints a record of the day, part, answer,
+input checksum and duration instead of just the answer.  --log prints the solver's traces to stderr,
+e.g. --log debug or --log warn,day_thirteen=trace.  check runs every input registered in ANSWERS
+(answers.toml by default) and reports the answers that do not match.  bench times parsing and
+both parts of every input registered in ANSWERS, or only PATH if it is given with --day.  generate
+prints a random input for day N, the same one for the same SEED (0 by default), about SCALE times
+the size of a real input (1 by default).  all runs both parts of every input registered in ANSWERS
+at once on N threads (one per core by default) and prints a table of the answers, timings and
+failures, panics included.  visualize writes an image of every step of solving PATH to DIRECTORY,
+with every cell SCALE pixels across (4 by default), for days 9, 11, 13 and 15";
+
+const DEFAULT_ANSWERS: &str = "answers.toml";
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    day: u8,
+    part: Part,
+    input: PathBuf,
+    /// Print a [`RunRecord`] as JSON rather than the bare answer
+    json: bool,
+    /// Nothing is logged when None
+    log: Option<Logger>,
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchArgs {
+    /// Every registered day when None
+    day: Option<u8>,
+    /// Bench this instead of the registered inputs.  Requires day
+    input: Option<PathBuf>,
+    answers: PathBuf,
+    options: BenchOptions,
+    format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+struct GenerateArgs {
+    day: u8,
+    seed: u64,
+    scale: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct AllArgs {
+    answers: PathBuf,
+    threads: usize,
+}
+
+#[derive(Debug, PartialEq)]
+struct VisualizeArgs {
+    day: u8,
+    input: PathBuf,
+    output: PathBuf,
+    scale: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Solve(Args),
+    Check(PathBuf),
+    Bench(BenchArgs),
+    Generate(GenerateArgs),
+    All(AllArgs),
+    Visualize(VisualizeArgs),
+}
+
+fn main() {
+    let command = match parse_command(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if let Command::Solve(Args {
+        log: Some(logger), ..
+    }) = &command
+    {
+        if let Err(error) = logger.clone().init() {
+            eprintln!("{:#}", error);
+            std::process::exit(1);
+        }
+    }
+
+    match command {
+        Command::Solve(args) => match solve(args.day, args.part, &args.input) {
+            Ok(record) if args.json => println!("{}", record.to_json()),
+            Ok(record) => println!("{}", record.answer),
+            Err(error) => {
+                eprintln!("day {} failed: {:#}", args.day, error);
+                std::process::exit(1);
+            }
+        },
+        Command::Check(answers_path) => match check(&answers_path) {
+            Ok(true) => (),
+            Ok(false) => std::process::exit(1),
+            Err(error) => {
+                eprintln!("check failed: {:#}", error);
+                std::process::exit(1);
+            }
+        },
+        Command::Bench(args) => {
+            if let Err(error) = run_bench(&args) {
+                eprintln!("bench failed: {:#}", error);
+                std::process::exit(1);
+            }
+        }
+        Command::Generate(args) => match aoc::find_generator(args.day) {
+            Some(generator) => print!("{}", generator(&mut Rng::new(args.seed), args.scale)),
+            None => {
+                eprintln!("day {} has no generator", args.day);
+                std::process::exit(1);
+            }
+        },
+        Command::All(args) => match run_every_day(&args) {
+            Ok(true) => (),
+            Ok(false) => std::process::exit(1),
+            Err(error) => {
+                eprintln!("all failed: {:#}", error);
+                std::process::exit(1);
+            }
+        },
+        Command::Visualize(args) => match visualize(&args) {
+            Ok(frames) => println!("wrote {} frames to {}", frames, args.output.display()),
+            Err(error) => {
+                eprintln!("day {} failed: {:#}", args.day, error);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+fn parse_command<I>(args: I) -> Result<Command>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("check") => {
+            args.next();
+            let answers_path = args.next().unwrap_or_else(|| DEFAULT_ANSWERS.to_string());
+            if let Some(extra) = args.next() {
+                return Err(anyhow!("Unexpected argument {:?}", extra));
+            }
+            Ok(Command::Check(PathBuf::from(answers_path)))
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench_args(args).map(Command::Bench)
+        }
+        Some("generate") => {
+            args.next();
+            parse_generate_args(args).map(Command::Generate)
+        }
+        Some("all") => {
+            args.next();
+            parse_all_args(args).map(Command::All)
+        }
+        Some("visualize") => {
+            args.next();
+            parse_visualize_args(args).map(Command::Visualize)
+        }
+        _ => parse_args(args).map(Command::Solve),
+    }
+}
+
+fn parse_bench_args<I>(args: I) -> Result<BenchArgs>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut bench_args = BenchArgs {
+        day: None,
+        input: None,
+        answers: PathBuf::from(DEFAULT_ANSWERS),
+        options: BenchOptions::default(),
+        format: Format::Table,
+    };
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{} is missing a value", flag))?;
+        match flag.as_str() {
+            "--day" => {
+                bench_args.day = Some(
+                    value
+                        .parse::<u8>()
+                        .context(format!("--day must be a number.  Got {:?}", value))?,
+                )
+            }
+            "--input" => bench_args.input = Some(PathBuf::from(value)),
+            "--answers" => bench_args.answers = PathBuf::from(value),
+            "--iterations" => {
+                bench_args.options.iterations = value
+                    .parse()
+                    .context(format!("--iterations must be a number.  Got {:?}", value))?
+            }
+            "--warm-up" => {
+                bench_args.options.warm_up = value
+                    .parse()
+                    .context(format!("--warm-up must be a number.  Got {:?}", value))?
+            }
+            "--format" => bench_args.format = value.parse()?,
+            _ => return Err(anyhow!("Unknown argument {:?}", flag)),
+        }
+    }
+
+    if bench_args.input.is_some() && bench_args.day.is_none() {
+        return Err(anyhow!("--input needs --day to know which day to bench"));
+    }
+
+    Ok(bench_args)
+}
+
+fn parse_generate_args<I>(args: I) -> Result<GenerateArgs>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut day = None;
+    let mut seed = 0;
+    let mut scale = 1;
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{} is missing a value", flag))?;
+        match flag.as_str() {
+            "--day" => {
+                day = Some(
+                    value
+                        .parse::<u8>()
+                        .context(format!("--day must be a number.  Got {:?}", value))?,
+                )
+            }
+            "--seed" => {
+                seed = value
+                    .parse()
+                    .context(format!("--seed must be a number.  Got {:?}", value))?
+            }
+            "--scale" => {
+                scale = value
+                    .parse()
+                    .context(format!("--scale must be a number.  Got {:?}", value))?
+            }
+            _ => return Err(anyhow!("Unknown argument {:?}", flag)),
+        }
+    }
+    if scale == 0 {
+        return Err(anyhow!("--scale must be at least 1"));
+    }
+
+    Ok(GenerateArgs {
+        day: day.ok_or_else(|| anyhow!("--day is required"))?,
+        seed,
+        scale,
+    })
+}
+
+fn parse_all_args<I>(args: I) -> Result<AllArgs>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut all_args = AllArgs {
+        answers: PathBuf::from(DEFAULT_ANSWERS),
+        threads: std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{} is missing a value", flag))?;
+        match flag.as_str() {
+            "--answers" => all_args.answers = PathBuf::from(value),
+            "--threads" => {
+                all_args.threads = value
+                    .parse()
+                    .context(format!("--threads must be a number.  Got {:?}", value))?
+            }
+            _ => return Err(anyhow!("Unknown argument {:?}", flag)),
+        }
+    }
+    if all_args.threads == 0 {
+        return Err(anyhow!("--threads must be at least 1"));
+    }
+
+    Ok(all_args)
+}
+
+fn parse_visualize_args<I>(args: I) -> Result<VisualizeArgs>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut day = None;
+    let mut input = None;
+    let mut output = None;
+    let mut scale = 4;
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{} is missing a value", flag))?;
+        match flag.as_str() {
+            "--day" => {
+                day = Some(
+                    value
+                        .parse::<u8>()
+                        .context(format!("--day must be a number.  Got {:?}", value))?,
+                )
+            }
+            "--input" => input = Some(PathBuf::from(value)),
+            "--output" => output = Some(PathBuf::from(value)),
+            "--scale" => {
+                scale = value
+                    .parse()
+                    .context(format!("--scale must be a number.  Got {:?}", value))?
+            }
+            _ => return Err(anyhow!("Unknown argument {:?}", flag)),
+        }
+    }
+    if scale == 0 {
+        return Err(anyhow!("--scale must be at least 1"));
+    }
+
+    Ok(VisualizeArgs {
+        day: day.ok_or_else(|| anyhow!("--day is required"))?,
+        input: input.ok_or_else(|| anyhow!("--input is required"))?,
+        output: output.ok_or_else(|| anyhow!("--output is required"))?,
+        scale,
+    })
+}
+
+fn parse_args<I>(args: I) -> Result<Args>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut json = false;
+    let mut log = None;
+
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{} is missing a value", flag))?;
+        match flag.as_str() {
+            "--day" => {
+                day = Some(
+                    value
+                        .parse::<u8>()
+                        .context(format!("--day must be a number.  Got {:?}", value))?,
+                )
+            }
+            "--part" => part = Some(value.parse::<Part>().context("--part must be 1 or 2")?),
+            "--input" => input = Some(PathBuf::from(value)),
+            "--format" => match value.as_str() {
+                "text" => json = false,
+                "json" => json = true,
+                _ => return Err(anyhow!("--format must be text or json.  Got {:?}", value)),
+            },
+            "--log" => log = Some(value.parse::<Logger>()?),
+            _ => return Err(anyhow!("Unknown argument {:?}", flag)),
+        }
+    }
+
+    Ok(Args {
+        day: day.ok_or_else(|| anyhow!("--day is required"))?,
+        part: part.ok_or_else(|| anyhow!("--part is required"))?,
+        input: input.ok_or_else(|| anyhow!("--input is required"))?,
+        json,
+        log,
+    })
+}
+
+/// Run a single part of a single day against the input located at input_path.  The duration
+/// covers parsing and solving but not reading the file
+fn solve(day: u8, part: Part, input_path: &Path) -> Result<RunRecord> {
+    let solution = aoc::find_day(day).ok_or_else(|| anyhow!("Day {} has not been solved", day))?;
+    let input = read_input(input_path)?;
+
+    let start = Instant::now();
+    let parsed = solution
+        .parse(&input)
+        .context(format!("Failed to parse {}", input_path.display()))?;
+    let answer = solution.run(part, parsed.as_ref())?;
+    let duration = start.elapsed();
+
+    Ok(RunRecord::new(day, part, answer, &input, duration))
+}
+
+/// Print every answer that does not match answers_path.  Returns whether they all matched
+fn check(answers_path: &Path) -> Result<bool> {
+    let checks = Answers::load(answers_path)?.check();
+    let correct = checks.iter().filter(|check| check.is_correct()).count();
+    for check in checks.iter().filter(|check| !check.is_correct()) {
+        println!("{}", check);
+    }
+    println!("{} of {} answers correct", correct, checks.len());
+
+    Ok(correct == checks.len())
+}
+
+/// Bench every selected input and write the measurements to stdout
+fn run_bench(args: &BenchArgs) -> Result<()> {
+    let mut inputs = Vec::new();
+    match (&args.input, args.day) {
+        (Some(input_path), Some(day)) => inputs.push((
+            day,
+            input_path.display().to_string(),
+            read_input(input_path)?,
+        )),
+        _ => {
+            let answers = Answers::load(&args.answers)?;
+            for (day, name, input) in answers.inputs() {
+                if args.day.is_none() || args.day == Some(day) {
+                    inputs.push((day, name.to_string(), read_input(&answers.path_of(input))?));
+                }
+            }
+        }
+    }
+    if inputs.is_empty() {
+        return Err(anyhow!("There are no inputs to bench"));
+    }
+
+    let mut measurements = Vec::new();
+    for (day, name, input) in inputs {
+        let solution =
+            aoc::find_day(day).ok_or_else(|| anyhow!("Day {} has not been solved", day))?;
+        measurements.extend(
+            bench(solution, &name, &input, args.options)
+                .context(format!("day {} ({})", day, name))?,
+        );
+    }
+
+    write_measurements(std::io::stdout().lock(), args.format, &measurements)
+}
+
+/// Run both parts of every registered input and print the summary.  Returns whether every part was
+/// solved
+fn run_every_day(args: &AllArgs) -> Result<bool> {
+    let answers = Answers::load(&args.answers)?;
+    let mut inputs = Vec::new();
+    for (day, name, input) in answers.inputs() {
+        let solution =
+            aoc::find_day(day).ok_or_else(|| anyhow!("Day {} has not been solved", day))?;
+        inputs.push((solution, name, read_input(&answers.path_of(input))?));
+    }
+
+    let tasks: Vec<Task> = inputs
+        .iter()
+        .flat_map(|(solution, name, input)| {
+            [Part::One, Part::Two].map(|part| Task {
+                solution: *solution,
+                input_name: name.to_string(),
+                input,
+                part,
+            })
+        })
+        .collect();
+    let results = run_all(&tasks, args.threads);
+    write_summary(std::io::stdout().lock(), &results)?;
+
+    Ok(results.iter().all(|result| result.outcome.is_ok()))
+}
+
+/// Write the frames of args.day solving args.input.  Returns how many were written
+fn visualize(args: &VisualizeArgs) -> Result<usize> {
+    let visualizer = aoc::find_visualizer(args.day)
+        .ok_or_else(|| anyhow!("Day {} cannot be visualized", args.day))?;
+    let input = read_input(&args.input)?;
+
+    let mut frames =
+        Frames::create(&args.output, &format!("day_{}", args.day))?.with_scale(args.scale);
+    visualizer(&input, &mut frames)?;
+
+    Ok(frames.len())
+}
+
+/// A path of - is stdin
+fn read_input(input_path: &Path) -> Result<String> {
+    if input_path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read stdin")?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(input_path)
+            .context(format!("Failed to read {}", input_path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_args, parse_command, solve, AllArgs, Args, BenchArgs, Command, GenerateArgs,
+        VisualizeArgs,
+    };
+    use aoc::bench::{BenchOptions, Format};
+    use aoc_common::{Answer, ParseError, Part};
+    use std::path::{Path, PathBuf};
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse_args(to_args(&["--day", "3", "--part", "2", "--input", "in.txt"]));
+        assert_eq!(
+            args.expect("valid arguments"),
+            Args {
+                day: 3,
+                part: Part::Two,
+                input: PathBuf::from("in.txt"),
+                json: false,
+                log: None
+            }
+        );
+
+        // Order of the flags should not matter
+        let args = parse_args(to_args(&[
+            "--input", "in.txt", "--part", "1", "--day", "16", "--format", "json",
+        ]));
+        assert_eq!(
+            args.expect("valid arguments"),
+            Args {
+                day: 16,
+                part: Part::One,
+                input: PathBuf::from("in.txt"),
+                json: true,
+                log: None
+            }
+        );
+
+        let args = parse_args(to_args(&[
+            "--day", "9", "--part", "2", "--input", "-", "--log", "debug",
+        ]));
+        assert_eq!(args.unwrap().log, Some("debug".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_parse_args_invalid() {
+        assert!(parse_args(to_args(&["--day", "3", "--part", "2"])).is_err());
+        assert!(parse_args(to_args(&["--day", "three", "--part", "2", "--input", "a"])).is_err());
+        assert!(parse_args(to_args(&["--day", "3", "--part", "3", "--input", "a"])).is_err());
+        assert!(parse_args(to_args(&["--day", "3", "--part"])).is_err());
+        assert!(parse_args(to_args(&["--dya", "3"])).is_err());
+        assert!(parse_args(to_args(&[
+            "--day", "3", "--part", "2", "--input", "a", "--format", "xml"
+        ]))
+        .is_err());
+        assert!(parse_args(to_args(&[
+            "--day", "3", "--part", "2", "--input", "a", "--log", "a=loud"
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command(to_args(&["check"])).unwrap(),
+            Command::Check(PathBuf::from("answers.toml"))
+        );
+        assert_eq!(
+            parse_command(to_args(&["check", "other.toml"])).unwrap(),
+            Command::Check(PathBuf::from("other.toml"))
+        );
+        assert!(parse_command(to_args(&["check", "a.toml", "b.toml"])).is_err());
+        assert!(matches!(
+            parse_command(to_args(&["--day", "3", "--part", "2", "--input", "in.txt"])),
+            Ok(Command::Solve(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_bench_command() {
+        assert_eq!(
+            parse_command(to_args(&["bench"])).unwrap(),
+            Command::Bench(BenchArgs {
+                day: None,
+                input: None,
+                answers: PathBuf::from("answers.toml"),
+                options: BenchOptions::default(),
+                format: Format::Table,
+            })
+        );
+        assert_eq!(
+            parse_command(to_args(&[
+                "bench",
+                "--day",
+                "12",
+                "--input",
+                "big.txt",
+                "--iterations",
+                "50",
+                "--warm-up",
+                "5",
+                "--format",
+                "csv"
+            ]))
+            .unwrap(),
+            Command::Bench(BenchArgs {
+                day: Some(12),
+                input: Some(PathBuf::from("big.txt")),
+                answers: PathBuf::from("answers.toml"),
+                options: BenchOptions {
+                    iterations: 50,
+                    warm_up: 5
+                },
+                format: Format::Csv,
+            })
+        );
+        assert!(parse_command(to_args(&["bench", "--input", "big.txt"])).is_err());
+        assert!(parse_command(to_args(&["bench", "--format", "xml"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate_command() {
+        assert_eq!(
+            parse_command(to_args(&["generate", "--day", "4"])).unwrap(),
+            Command::Generate(GenerateArgs {
+                day: 4,
+                seed: 0,
+                scale: 1
+            })
+        );
+        assert_eq!(
+            parse_command(to_args(&[
+                "generate", "--scale", "100", "--day", "12", "--seed", "2021"
+            ]))
+            .unwrap(),
+            Command::Generate(GenerateArgs {
+                day: 12,
+                seed: 2021,
+                scale: 100
+            })
+        );
+        assert!(parse_command(to_args(&["generate"])).is_err());
+        assert!(parse_command(to_args(&["generate", "--day", "4", "--scale", "0"])).is_err());
+        assert!(parse_command(to_args(&["generate", "--day", "4", "--seed", "-1"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_all_command() {
+        assert!(matches!(
+            parse_command(to_args(&["all"])),
+            Ok(Command::All(AllArgs { threads, .. })) if threads > 0
+        ));
+        assert_eq!(
+            parse_command(to_args(&["all", "--threads", "4", "--answers", "a.toml"])).unwrap(),
+            Command::All(AllArgs {
+                answers: PathBuf::from("a.toml"),
+                threads: 4
+            })
+        );
+        assert!(parse_command(to_args(&["all", "--threads", "0"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_visualize_command() {
+        assert_eq!(
+            parse_command(to_args(&[
+                "visualize",
+                "--day",
+                "13",
+                "--input",
+                "in.txt",
+                "--output",
+                "frames"
+            ]))
+            .unwrap(),
+            Command::Visualize(VisualizeArgs {
+                day: 13,
+                input: PathBuf::from("in.txt"),
+                output: PathBuf::from("frames"),
+                scale: 4
+            })
+        );
+        assert!(
+            parse_command(to_args(&["visualize", "--day", "13", "--input", "in.txt"])).is_err()
+        );
+        assert!(parse_command(to_args(&[
+            "visualize",
+            "--day",
+            "13",
+            "--input",
+            "a",
+            "--output",
+            "b",
+            "--scale",
+            "0"
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn test_solve_example() {
+        let answer = solve(
+            3,
+            Part::One,
+            Path::new("../day_three/src/example_input.txt"),
+        );
+        let record = answer.expect("day three solves");
+        assert_eq!(record.answer, Answer::Unsigned(198));
+        assert_eq!((record.day, record.part), (3, Part::One));
+        assert_eq!(record.answer_type, "unsigned");
+
+        let answer = solve(
+            15,
+            Part::Two,
+            Path::new("../day_fifteen/src/test_input.txt"),
+        );
+        assert_eq!(
+            answer.expect("day fifteen solves").answer,
+            Answer::Unsigned(315)
+        );
+    }
+
+    #[test]
+    fn test_solve_unknown_day() {
+        assert!(solve(
+            26,
+            Part::One,
+            Path::new("../day_three/src/example_input.txt")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_solve_malformed_input() {
+        let error =
+            solve(3, Part::One, Path::new("../day_fifteen/src/test_input.txt")).unwrap_err();
+
+        assert!(format!("{:#}", error).starts_with("Failed to parse"));
+        assert_eq!(
+            error
+                .downcast_ref::<ParseError>()
+                .and_then(ParseError::line),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! A machine readable record of a single run, for scripts and dashboards that should not have to
+//! scrape the plain text output.
+use aoc_common::{Answer, Part};
+use serde::{Serialize, Serializer};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    #[serde(serialize_with = "serialize_part")]
+    pub part: Part,
+    /// Numbers stay numbers and drawn answers are strings
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Answer,
+    pub answer_type: &'static str,
+    /// See [`checksum`]
+    pub input_checksum: String,
+    /// Parsing and solving together
+    pub duration_ns: u64,
+}
+
+impl RunRecord {
+    pub fn new(day: u8, part: Part, answer: Answer, input: &str, duration: Duration) -> Self {
+        RunRecord {
+            day,
+            part,
+            answer_type: answer.type_name(),
+            answer,
+            input_checksum: checksum(input.as_bytes()),
+            duration_ns: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a record always serializes")
+    }
+}
+
+/// 64 bit FNV-1a of input as 16 hex digits.  Only meant to tell inputs apart, not to be secure
+pub fn checksum(input: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let hash = input.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+fn serialize_part<S>(part: &Part, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match part {
+        Part::One => serializer.serialize_u8(1),
+        Part::Two => serializer.serialize_u8(2),
+    }
+}
+
+fn serialize_answer<S>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match answer {
+        Answer::Unsigned(value) => serializer.serialize_u64(*value),
+        Answer::Signed(value) => serializer.serialize_i64(*value),
+        Answer::Text(value) => serializer.serialize_str(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::record::{checksum, RunRecord};
+    use aoc_common::{Answer, Part};
+    use std::time::Duration;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), "cbf29ce484222325");
+        assert_eq!(checksum(b"a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum(b"1,2"), checksum(b"2,1"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let record = RunRecord::new(
+            7,
+            Part::Two,
+            Answer::Signed(-168),
+            "",
+            Duration::from_micros(3),
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":7,"part":2,"answer":-168,"answer_type":"signed","input_checksum":"cbf29ce484222325","duration_ns":3000}"#
+        );
+
+        let record = RunRecord::new(
+            13,
+            Part::Two,
+            Answer::Text("#.\n".to_string()),
+            "",
+            Duration::ZERO,
+        );
+        let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+        assert_eq!(json["answer"], "#.\n");
+        assert_eq!(json["answer_type"], "text");
+    }
+}
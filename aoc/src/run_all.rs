@@ -0,0 +1,285 @@
+//! Run every part of every registered input at once on a pool of threads.
+//!
+//! Each part runs on its own, parse included, and a panic is caught and reported like an error
+//! so one broken day cannot take the others down with it.
+use anyhow::Result;
+use aoc_common::{Answer, DynSolution, Part};
+use std::any::Any;
+use std::fmt;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::time::{Duration, Instant};
+
+/// One part of one day on one input
+pub struct Task<'a> {
+    pub solution: &'a dyn DynSolution,
+    /// Only used to label the result
+    pub input_name: String,
+    pub input: &'a str,
+    pub part: Part,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Parsing or the part returned an error
+    Error(String),
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "failed: {}", error),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskResult {
+    pub day: u8,
+    pub input_name: String,
+    pub part: Part,
+    pub outcome: Result<Answer, Failure>,
+    /// Parsing and solving together
+    pub duration: Duration,
+}
+
+/// Run every task on threads threads, at least one.  The results are in the same order as tasks
+pub fn run_all(tasks: &[Task], threads: usize) -> Vec<TaskResult> {
+    let next_task = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(tasks.len()));
+
+    silence_worker_panics();
+    std::thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, tasks.len().max(1)) {
+            let worker = std::thread::Builder::new().name(WORKER_NAME.to_string());
+            let spawned = worker.spawn_scoped(scope, || loop {
+                let index = next_task.fetch_add(1, Ordering::Relaxed);
+                let task = match tasks.get(index) {
+                    Some(task) => task,
+                    None => break,
+                };
+                let result = run_task(task);
+                results
+                    .lock()
+                    .expect("a task never panics while holding the lock")
+                    .push((index, result));
+            });
+            spawned.expect("a worker thread can be spawned");
+        }
+    });
+
+    let mut results = results
+        .into_inner()
+        .expect("a task never panics while holding the lock");
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Workers are named so the panic hook can tell their panics from every other thread's
+const WORKER_NAME: &str = "aoc-run-all";
+
+/// The default hook would print every panic caught in a worker in the middle of the other days'
+/// output, so leave those out and pass every other thread's panic on to the hook that was there
+/// before.  This is installed once and never swapped back, so no other thread ever panics while
+/// the hook is missing
+fn silence_worker_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if std::thread::current().name() != Some(WORKER_NAME) {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn run_task(task: &Task) -> TaskResult {
+    let start = Instant::now();
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = task.solution.parse(task.input)?;
+        task.solution.run(task.part, parsed.as_ref())
+    }));
+    let duration = start.elapsed();
+
+    TaskResult {
+        day: task.solution.day(),
+        input_name: task.input_name.clone(),
+        part: task.part,
+        outcome: match outcome {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(error)) => Err(Failure::Error(format!("{:#}", error))),
+            Err(payload) => Err(Failure::Panic(panic_message(payload.as_ref()))),
+        },
+        duration,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// A table of every result followed by any answer that needs more than one line, which are drawn
+/// in full under the table
+pub fn write_summary<W>(mut writer: W, results: &[TaskResult]) -> Result<()>
+where
+    W: Write,
+{
+    writeln!(
+        writer,
+        "{:>3}  {:<12}  {:>4}  {:<20}  {:>12}  status",
+        "day", "input", "part", "answer", "time"
+    )?;
+    for result in results {
+        let (answer, status) = match &result.outcome {
+            Ok(Answer::Text(text)) if text.trim_end().contains('\n') => (
+                format!("({} lines)", text.trim_end().lines().count()),
+                "ok".to_string(),
+            ),
+            Ok(answer) => (answer.to_string(), "ok".to_string()),
+            Err(failure) => (String::new(), failure.to_string()),
+        };
+        writeln!(
+            writer,
+            "{:>3}  {:<12}  {:>4}  {:<20}  {:>12}  {}",
+            result.day,
+            result.input_name,
+            result.part.to_string(),
+            answer,
+            format!("{:?}", result.duration),
+            status
+        )?;
+    }
+
+    let failed = results
+        .iter()
+        .filter(|result| result.outcome.is_err())
+        .count();
+    writeln!(
+        writer,
+        "\n{} of {} parts solved, {} failed",
+        results.len() - failed,
+        results.len(),
+        failed
+    )?;
+
+    for result in results {
+        if let Ok(Answer::Text(text)) = &result.outcome {
+            if text.trim_end().contains('\n') {
+                writeln!(
+                    writer,
+                    "\nday {} part {} ({}):\n{}",
+                    result.day,
+                    result.part,
+                    result.input_name,
+                    text.trim_end()
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find_day;
+    use crate::run_all::{run_all, write_summary, Failure, Task};
+    use anyhow::Result;
+    use aoc_common::{Answer, ParseResult, Part, Solution};
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const DAY: u8 = 99;
+        type Input = String;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+            Ok(input.len())
+        }
+
+        fn part_two(_input: &Self::Input) -> Result<Self::PartTwo> {
+            panic!("unfinished")
+        }
+    }
+
+    #[test]
+    fn test_panics_are_isolated() {
+        let day_seven = find_day(7).unwrap();
+        let mut tasks = Vec::new();
+        for part in [Part::One, Part::Two] {
+            tasks.push(Task {
+                solution: &Unfinished,
+                input_name: "example".to_string(),
+                input: "abc",
+                part,
+            });
+            tasks.push(Task {
+                solution: day_seven,
+                input_name: "example".to_string(),
+                input: "16,1,2,0,4,2,7,1,2,14",
+                part,
+            });
+        }
+        tasks.push(Task {
+            solution: day_seven,
+            input_name: "bad".to_string(),
+            input: "1,x",
+            part: Part::One,
+        });
+
+        let results = run_all(&tasks, 3);
+        let outcomes: Vec<_> = results
+            .iter()
+            .map(|result| (result.day, result.part, result.outcome.clone()))
+            .collect();
+        assert_eq!(outcomes[0], (99, Part::One, Ok(Answer::Unsigned(3))));
+        assert_eq!(outcomes[1], (7, Part::One, Ok(Answer::Signed(37))));
+        assert_eq!(
+            outcomes[2],
+            (99, Part::Two, Err(Failure::Panic("unfinished".to_string())))
+        );
+        assert_eq!(outcomes[3], (7, Part::Two, Ok(Answer::Signed(168))));
+        assert!(matches!(outcomes[4].2, Err(Failure::Error(_))));
+    }
+
+    #[test]
+    fn test_write_summary() {
+        let day_thirteen = find_day(13).unwrap();
+        let input = std::fs::read_to_string("../day_thirteen/src/example_input.txt").unwrap();
+        let tasks: Vec<_> = [Part::One, Part::Two]
+            .into_iter()
+            .map(|part| Task {
+                solution: day_thirteen,
+                input_name: "example".to_string(),
+                input: &input,
+                part,
+            })
+            .collect();
+
+        let mut summary = Vec::new();
+        write_summary(&mut summary, &run_all(&tasks, 1)).unwrap();
+        let summary = String::from_utf8(summary).unwrap();
+        let lines: Vec<_> = summary.lines().collect();
+        assert!(lines[1].starts_with(" 13  example          1  17 "));
+        assert!(lines[2].contains("(5 lines)"));
+        assert_eq!(lines[4], "2 of 2 parts solved, 0 failed");
+        assert_eq!(lines[6], "day 13 part 2 (example):");
+    }
+}
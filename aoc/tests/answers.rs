@@ -0,0 +1,17 @@
+use aoc::answers::Answers;
+
+#[test]
+fn test_registered_answers() {
+    let answers = Answers::load(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml"))
+        .expect("answers.toml loads");
+
+    let checks = answers.check();
+    assert!(!checks.is_empty());
+
+    let wrong: Vec<String> = checks
+        .iter()
+        .filter(|check| !check.is_correct())
+        .map(|check| check.to_string())
+        .collect();
+    assert!(wrong.is_empty(), "\n{}", wrong.join("\n"));
+}
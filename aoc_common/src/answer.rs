@@ -0,0 +1,88 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Most answers are numbers, but some days (the folded paper of day thirteen) draw their answer
+/// instead, so the type of the answer is kept around rather than turning everything into a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// unsigned, signed or text.  For tooling that needs to know how to read the answer back
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(1195_usize).to_string(), "1195");
+        assert_eq!(Answer::from(-25_i32).to_string(), "-25");
+        assert_eq!(Answer::from("#.#\n".to_string()).to_string(), "#.#\n");
+    }
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(Answer::from(1195_usize).type_name(), "unsigned");
+        assert_eq!(Answer::from(-25_i32).type_name(), "signed");
+        assert_eq!(Answer::from(String::new()).type_name(), "text");
+    }
+}
@@ -0,0 +1,46 @@
+use crate::ParseResult;
+use std::io::BufRead;
+
+/// Every line of reader.
+///
+/// Unlike `lines().flatten()` a line that fails to read is reported instead of quietly ending the
+/// input early.
+pub fn read_lines<R>(reader: R) -> ParseResult<Vec<String>>
+where
+    R: BufRead,
+{
+    Ok(reader.lines().collect::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{read_lines, ParseError};
+    use std::io::{BufReader, Read};
+
+    /// Hands out a single line and then fails
+    struct FailingReader(bool);
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.0 {
+                Err(std::io::Error::other("disconnected"))
+            } else {
+                self.0 = true;
+                buf[..3].copy_from_slice(b"12\n");
+                Ok(3)
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_lines() {
+        assert_eq!(
+            read_lines("1\n2\r\n3".as_bytes()).unwrap(),
+            vec!["1", "2", "3"]
+        );
+        assert!(matches!(
+            read_lines(BufReader::new(FailingReader(false))),
+            Err(ParseError::Io(_))
+        ));
+    }
+}
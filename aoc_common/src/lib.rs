@@ -0,0 +1,12 @@
+//! Pieces shared by every day so that tooling can treat them the same way.
+mod answer;
+mod input;
+mod parse_error;
+mod rng;
+mod solution;
+
+pub use answer::Answer;
+pub use input::read_lines;
+pub use parse_error::{ParseError, ParseResult};
+pub use rng::Rng;
+pub use solution::{DynSolution, Part, Solution};
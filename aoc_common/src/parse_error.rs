@@ -0,0 +1,120 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Why an input could not be turned into something a day can solve.
+///
+/// Lines and columns are counted from 1 so that they match what an editor shows.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The input could not be read at all
+    Io(io::Error),
+    /// The input ran out while `expected` was still missing
+    UnexpectedEnd { line: usize, expected: String },
+    /// `text` was found where something of the form `expected` should have been
+    Invalid {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// line_index and column_index are zero based, the way `enumerate` hands them out
+    pub fn invalid<T, E>(line_index: usize, column_index: usize, text: T, expected: E) -> Self
+    where
+        T: Into<String>,
+        E: Into<String>,
+    {
+        ParseError::Invalid {
+            line: line_index + 1,
+            column: column_index + 1,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// line_index is zero based and is the line that should have held expected
+    pub fn unexpected_end<E>(line_index: usize, expected: E) -> Self
+    where
+        E: Into<String>,
+    {
+        ParseError::UnexpectedEnd {
+            line: line_index + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// The line the error was found on.  None for errors reading the input
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            ParseError::Io(_) => None,
+            ParseError::UnexpectedEnd { line, .. } | ParseError::Invalid { line, .. } => {
+                Some(*line)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io(error) => write!(f, "Failed to read input: {}", error),
+            ParseError::UnexpectedEnd { line, expected } => {
+                write!(f, "line {}: input ended, expected {}", line, expected)
+            }
+            ParseError::Invalid {
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found {:?}",
+                line, column, expected, text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(error: io::Error) -> Self {
+        ParseError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::invalid(2, 0, "x", "a binary digit").to_string(),
+            "line 3, column 1: expected a binary digit, found \"x\""
+        );
+        assert_eq!(
+            ParseError::unexpected_end(0, "a comma separated list").to_string(),
+            "line 1: input ended, expected a comma separated list"
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        assert_eq!(ParseError::invalid(4, 7, "", "").line(), Some(5));
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        assert_eq!(ParseError::from(io_error).line(), None);
+    }
+}
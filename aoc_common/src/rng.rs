@@ -0,0 +1,84 @@
+use std::ops::Range;
+
+/// A small seeded pseudo random number generator (SplitMix64) for the input generators.
+///
+/// The same seed always gives the same numbers on every platform, so a generated input that
+/// breaks a solver can be reproduced from its seed alone.  It is not suitable for anything that
+/// needs real randomness.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in range.  The slight bias towards the low end of very large ranges is ignored.
+    /// Panics if range is empty
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(
+            !range.is_empty(),
+            "cannot pick from the empty range {:?}",
+            range
+        );
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+
+    /// True with the given probability, which should be between 0 and 1
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // The top 53 bits are all an f64 can hold
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < probability
+    }
+
+    /// Panics if items is empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rng;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut first = Rng::new(2021);
+        let mut second = Rng::new(2021);
+        assert!((0..5).all(|_| first.next_u64() == second.next_u64()));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(6);
+        let picks: Vec<usize> = (0..1000).map(|_| rng.range(3..7)).collect();
+        assert!(picks.iter().all(|pick| (3..7).contains(pick)));
+        assert!((3..7).all(|number| picks.contains(&number)));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(14);
+        let mut numbers: Vec<usize> = (0..50).collect();
+        rng.shuffle(&mut numbers);
+        assert_ne!(numbers, (0..50).collect::<Vec<_>>());
+        numbers.sort_unstable();
+        assert_eq!(numbers, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{Answer, ParseResult};
+use anyhow::{anyhow, Result};
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(anyhow!("part must be 1 or 2.  Got {:?}", other)),
+        }
+    }
+}
+
+/// A single day of the advent calendar.
+///
+/// The input is parsed once and then borrowed by both parts so that the parts can be run (and
+/// timed) independently of parsing.
+pub trait Solution {
+    /// Which day of the calendar this solves
+    const DAY: u8;
+
+    type Input;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    /// Malformed input is reported rather than panicking so a bad file never takes the runner down
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// An object safe version of [`Solution`] so that every day can live in one table.
+///
+/// This is implemented for every [`Solution`] so days should never need to implement it
+/// themselves.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// input must have been created by [`DynSolution::parse`] of the same day
+    fn part_one(&self, input: &dyn Any) -> Result<Answer>;
+
+    /// input must have been created by [`DynSolution::parse`] of the same day
+    fn part_two(&self, input: &dyn Any) -> Result<Answer>;
+
+    /// Run the requested part on input that has already been parsed
+    fn run(&self, part: Part, input: &dyn Any) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Parse input and then run the requested part on it
+    fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        let parsed = self.parse(input)?;
+        self.run(part, parsed.as_ref())
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer> {
+        Ok(S::part_one(downcast_input::<S>(input)?)?.into())
+    }
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer> {
+        Ok(S::part_two(downcast_input::<S>(input)?)?.into())
+    }
+}
+
+fn downcast_input<S>(input: &dyn Any) -> Result<&S::Input>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("Input was not parsed by day {}", S::DAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, DynSolution, ParseError, ParseResult, Part, Solution};
+    use anyhow::Result;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(line_index, line)| {
+                    line.parse()
+                        .map_err(|_| ParseError::invalid(line_index, 0, line, "a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+            Ok(format!("{:?}", input))
+        }
+    }
+
+    struct Other;
+
+    impl Solution for Other {
+        const DAY: u8 = 2;
+        type Input = String;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_dyn_solve() {
+        let days: [&dyn DynSolution; 2] = [&Sum, &Other];
+
+        assert_eq!(days[0].day(), 1);
+        assert_eq!(
+            days[0].solve(Part::One, "1\n2\n3").unwrap(),
+            Answer::Unsigned(6)
+        );
+        assert_eq!(
+            days[0].solve(Part::Two, "1\n2").unwrap(),
+            Answer::Text("[1, 2]".to_string())
+        );
+        assert_eq!(
+            days[1].solve(Part::Two, "abc").unwrap(),
+            Answer::Unsigned(3)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = DynSolution::parse(&Sum, "1\nx").unwrap_err();
+        assert_eq!(
+            error
+                .downcast_ref::<ParseError>()
+                .and_then(ParseError::line),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_wrong_input_type() {
+        let parsed = DynSolution::parse(&Other, "abc").unwrap();
+        assert!(DynSolution::part_one(&Sum, parsed.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
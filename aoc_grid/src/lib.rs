@@ -0,0 +1,452 @@
+//! A rectangular grid for the puzzles whose input is a map of cells.
+//!
+//! Positions are always `(row, column)` with `(0, 0)` in the top left corner.
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult};
+use std::ops::{Index, IndexMut};
+
+pub type Position = (usize, usize);
+
+/// Up, left, down, right
+const FOUR_WAY: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+
+/// Every cell touching the center, including diagonals
+const EIGHT_WAY: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid with every cell set to value.  A grid with no columns has no rows either.
+    ///
+    /// Panics if width * height overflows, like a `vec!` that size would
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        let height = if width == 0 { 0 } else { height };
+        let len = width
+            .checked_mul(height)
+            .expect("width * height must fit in a usize");
+        Grid {
+            cells: vec![value; len],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every row must have the same number of columns.  Rows with no columns make an empty
+    /// grid, like [`Grid::from_vec`] with a width of 0
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = if width == 0 { 0 } else { rows.len() };
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (row_index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(anyhow!(
+                    "Row {} has {} columns, expected {}",
+                    row_index,
+                    row.len(),
+                    width
+                ));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// cells are in row-major order so cells.len() must be a multiple of width
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self> {
+        if width == 0 {
+            return if cells.is_empty() {
+                Ok(Grid {
+                    cells,
+                    width,
+                    height: 0,
+                })
+            } else {
+                Err(anyhow!("A grid with no columns cannot contain cells"))
+            };
+        }
+        if !cells.len().is_multiple_of(width) {
+            return Err(anyhow!(
+                "{} cells cannot be split into rows of {}",
+                cells.len(),
+                width
+            ));
+        }
+
+        Ok(Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            self.cells.get(position.0 * self.width + position.1)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            self.cells.get_mut(position.0 * self.width + position.1)
+        } else {
+            None
+        }
+    }
+
+    /// The cells above, left, below and right of position that are inside the grid
+    pub fn neighbors4(&self, position: Position) -> Neighbors {
+        Neighbors::new(position, self.width, self.height, &FOUR_WAY)
+    }
+
+    /// The eight cells surrounding position that are inside the grid
+    pub fn neighbors8(&self, position: Position) -> Neighbors {
+        Neighbors::new(position, self.width, self.height, &EIGHT_WAY)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Top to bottom.  Empty if column is outside of the grid
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let height = if column < self.width { self.height } else { 0 };
+        (0..height).map(move |row| &self.cells[row * self.width + column])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.column(column))
+    }
+
+    /// Every cell in row-major order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every position in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of a {}x{} grid",
+                position, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, height, width))
+    }
+}
+
+/// Iterator over the in bounds neighbors of a single position
+pub struct Neighbors {
+    origin: Position,
+    width: usize,
+    height: usize,
+    offsets: &'static [(isize, isize)],
+}
+
+impl Neighbors {
+    fn new(
+        origin: Position,
+        width: usize,
+        height: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> Self {
+        Neighbors {
+            origin,
+            width,
+            height,
+            offsets,
+        }
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(((row_offset, column_offset), rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+            let row = self.origin.0.checked_add_signed(*row_offset);
+            let column = self.origin.1.checked_add_signed(*column_offset);
+            if let (Some(row), Some(column)) = (row, column) {
+                if row < self.height && column < self.width {
+                    return Some((row, column));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Parse a grid where every cell is a single digit.  Blank lines are skipped
+pub fn parse_digits<T>(input: &str) -> ParseResult<Grid<T>>
+where
+    T: From<u8>,
+{
+    let mut width = None;
+    let mut cells = Vec::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let row_width = line.chars().count();
+        match width {
+            None => width = Some(row_width),
+            Some(width) if width != row_width => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    format!("a row of {} digits", width),
+                ))
+            }
+            Some(_) => (),
+        }
+
+        for (column_index, digit) in line.chars().enumerate() {
+            match digit.to_digit(10) {
+                Some(digit) => cells.push(T::from(digit as u8)),
+                None => {
+                    return Err(ParseError::invalid(
+                        line_index,
+                        column_index,
+                        digit,
+                        "a digit",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(Grid::from_vec(width.unwrap_or(0), cells).expect("every row has the same width"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_digits, Grid};
+
+    fn three_by_two() -> Grid<usize> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(3, 2, 7);
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+        let no_columns = Grid::new(0, 3, 7);
+        assert_eq!((no_columns.width(), no_columns.height()), (0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_overflow() {
+        Grid::new(usize::MAX, 2, 0_u8);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = three_by_two();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.len(), 6);
+
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Grid::<usize>::from_rows(vec![]).unwrap().is_empty());
+
+        let no_columns = Grid::<usize>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((no_columns.width(), no_columns.height()), (0, 0));
+        assert_eq!(no_columns, Grid::from_vec(0, vec![]).unwrap());
+        assert!(Grid::from_rows(vec![vec![], vec![1]]).is_err());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        assert_eq!(
+            Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]).unwrap(),
+            three_by_two()
+        );
+        assert!(Grid::from_vec(4, vec![1, 2, 3, 4, 5, 6]).is_err());
+        assert!(Grid::from_vec(0, vec![1]).is_err());
+    }
+
+    #[test]
+    fn test_checked_indexing() {
+        let mut grid = three_by_two();
+        assert_eq!(grid.get((0, 0)), Some(&1));
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        grid[(1, 1)] = 50;
+        assert_eq!(grid[(1, 1)], 50);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = three_by_two();
+        let _ = grid[(0, 3)];
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        let grid = Grid::new(3, 3, 0);
+        let mut corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        corner.sort_unstable();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let mut center: Vec<_> = grid.neighbors4((1, 1)).collect();
+        center.sort_unstable();
+        assert_eq!(center, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+
+        assert_eq!(grid.neighbors4((2, 2)).count(), 2);
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert!(!grid.neighbors8((1, 1)).any(|position| position == (1, 1)));
+
+        let single = Grid::new(1, 1, 0);
+        assert_eq!(single.neighbors8((0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = three_by_two();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.sum::<usize>())
+                .collect::<Vec<_>>(),
+            vec![5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let grid = three_by_two();
+        let indexed: Vec<_> = grid.indexed_iter().collect();
+        assert_eq!(indexed[0], ((0, 0), &1));
+        assert_eq!(indexed[4], ((1, 1), &5));
+        assert_eq!(grid.map(|cell| cell * 2).iter().sum::<usize>(), 42);
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid: Grid<usize> = parse_digits("123\n456\n").unwrap();
+        assert_eq!(grid, three_by_two());
+
+        assert_eq!(
+            parse_digits::<usize>("12a\n456").unwrap_err().to_string(),
+            "line 1, column 3: expected a digit, found \"a\""
+        );
+        assert_eq!(
+            parse_digits::<usize>("123\n\n45").unwrap_err().line(),
+            Some(3)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::Rgb;
+
+/// Turns a number into a color by blending between a few fixed colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMap {
+    /// Black to white
+    Grayscale,
+    /// Black through red and yellow to white, for energy levels
+    Heat,
+    /// Dark blue through green to yellow, for heights and costs
+    Viridis,
+}
+
+impl ColorMap {
+    fn stops(&self) -> &'static [Rgb] {
+        match self {
+            ColorMap::Grayscale => &[Rgb::BLACK, Rgb::WHITE],
+            ColorMap::Heat => &[Rgb::BLACK, Rgb(200, 30, 0), Rgb(255, 210, 0), Rgb::WHITE],
+            ColorMap::Viridis => &[
+                Rgb(68, 1, 84),
+                Rgb(59, 82, 139),
+                Rgb(33, 145, 140),
+                Rgb(94, 201, 98),
+                Rgb(253, 231, 37),
+            ],
+        }
+    }
+
+    /// The color at fraction of the way along the map.  fraction is clamped to between 0 and 1
+    pub fn color(&self, fraction: f64) -> Rgb {
+        let stops = self.stops();
+        let position = fraction.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let index = (position as usize).min(stops.len() - 2);
+        let blend = position - index as f64;
+
+        let Rgb(from_red, from_green, from_blue) = stops[index];
+        let Rgb(to_red, to_green, to_blue) = stops[index + 1];
+        let mix =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * blend).round() as u8;
+        Rgb(
+            mix(from_red, to_red),
+            mix(from_green, to_green),
+            mix(from_blue, to_blue),
+        )
+    }
+
+    /// The color of value where min is the start of the map and max the end
+    pub fn color_between(&self, value: f64, min: f64, max: f64) -> Rgb {
+        if max <= min {
+            return self.color(0.0);
+        }
+        self.color((value - min) / (max - min))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorMap, Rgb};
+
+    #[test]
+    fn test_color() {
+        assert_eq!(ColorMap::Grayscale.color(0.0), Rgb::BLACK);
+        assert_eq!(ColorMap::Grayscale.color(0.5), Rgb::gray(128));
+        assert_eq!(ColorMap::Grayscale.color(1.0), Rgb::WHITE);
+        assert_eq!(ColorMap::Heat.color(2.0), Rgb::WHITE);
+        assert_eq!(ColorMap::Viridis.color(-1.0), Rgb(68, 1, 84));
+        assert_eq!(ColorMap::Viridis.color(0.25), Rgb(59, 82, 139));
+    }
+
+    #[test]
+    fn test_color_between() {
+        assert_eq!(ColorMap::Grayscale.color_between(9.0, 0.0, 9.0), Rgb::WHITE);
+        assert_eq!(ColorMap::Grayscale.color_between(3.0, 3.0, 3.0), Rgb::BLACK);
+    }
+}
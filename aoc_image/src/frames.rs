@@ -0,0 +1,98 @@
+use crate::Image;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// A numbered sequence of images in one directory, one for each step of a puzzle.
+///
+/// Frames are named `<prefix>_00000.<extension>`, `<prefix>_00001.<extension>` and so on, so they
+/// sort in order and can be turned into a video with something like
+/// `ffmpeg -i <prefix>_%05d.ppm steps.mp4`.
+#[derive(Debug)]
+pub struct Frames {
+    directory: PathBuf,
+    prefix: String,
+    /// See [`Image::scaled`]
+    scale: usize,
+    written: usize,
+}
+
+impl Frames {
+    /// Creates directory if it does not exist yet
+    pub fn create<P>(directory: P, prefix: &str) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)
+            .context(format!("Failed to create {}", directory.display()))?;
+
+        Ok(Frames {
+            directory: directory.to_path_buf(),
+            prefix: prefix.to_string(),
+            scale: 1,
+            written: 0,
+        })
+    }
+
+    /// Scale every frame up by factor before it is written
+    pub fn with_scale(mut self, factor: usize) -> Self {
+        self.scale = factor.max(1);
+        self
+    }
+
+    /// Write image as the next frame.  Returns where it was written
+    pub fn push(&mut self, image: &Image) -> Result<PathBuf> {
+        let path = self.directory.join(format!(
+            "{}_{:05}.{}",
+            self.prefix,
+            self.written,
+            image.extension()
+        ));
+        if self.scale == 1 {
+            image.save(&path)?;
+        } else {
+            image.scaled(self.scale).save(&path)?;
+        }
+        self.written += 1;
+
+        Ok(path)
+    }
+
+    /// The number of frames written so far
+    pub fn len(&self) -> usize {
+        self.written
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.written == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Frames, Image, Rgb};
+    use aoc_grid::Grid;
+
+    #[test]
+    fn test_frames() {
+        let directory =
+            std::env::temp_dir().join(format!("aoc_image_frames_{}", std::process::id()));
+        let mut frames = Frames::create(&directory, "dots").unwrap().with_scale(3);
+        assert!(frames.is_empty());
+
+        let first = frames.push(&Image::Bitmap(Grid::new(2, 2, true))).unwrap();
+        let second = frames.push(&Image::Graymap(Grid::new(1, 1, 9))).unwrap();
+        assert_eq!(first, directory.join("dots_00000.pbm"));
+        assert_eq!(second, directory.join("dots_00001.pgm"));
+        assert_eq!(frames.len(), 2);
+        assert!(std::fs::read(&second)
+            .unwrap()
+            .starts_with(b"P5\n3 3\n255\n"));
+
+        assert!(frames
+            .push(&Image::Pixmap(Grid::new(0, 0, Rgb::RED)))
+            .is_err());
+        assert_eq!(frames.len(), 2);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+//! Write grids as Netpbm images so a puzzle's state can be looked at rather than read.
+//!
+//! PBM, PGM and PPM are about the simplest image formats there are, which keeps this free of
+//! dependencies, and most image viewers (and ffmpeg, for a sequence of [`Frames`]) open them.
+//! Pixels are `(row, column)` like every other [`Grid`].
+mod color_map;
+mod frames;
+
+pub use color_map::ColorMap;
+pub use frames::Frames;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_grid::Grid;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+
+    /// The same shade of gray as level in a graymap
+    pub fn gray(level: u8) -> Self {
+        Rgb(level, level, level)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// A PBM.  true is black, as it is in the format
+    Bitmap(Grid<bool>),
+    /// A PGM where 0 is black and 255 is white
+    Graymap(Grid<u8>),
+    /// A PPM
+    Pixmap(Grid<Rgb>),
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        match self {
+            Image::Bitmap(grid) => grid.width(),
+            Image::Graymap(grid) => grid.width(),
+            Image::Pixmap(grid) => grid.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        match self {
+            Image::Bitmap(grid) => grid.height(),
+            Image::Graymap(grid) => grid.height(),
+            Image::Pixmap(grid) => grid.height(),
+        }
+    }
+
+    /// pbm, pgm or ppm
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Bitmap(_) => "pbm",
+            Image::Graymap(_) => "pgm",
+            Image::Pixmap(_) => "ppm",
+        }
+    }
+
+    /// Every pixel becomes a factor by factor square.  Puzzle grids are often only a few cells
+    /// across, which is too small to see
+    pub fn scaled(&self, factor: usize) -> Image {
+        match self {
+            Image::Bitmap(grid) => Image::Bitmap(scale(grid, factor)),
+            Image::Graymap(grid) => Image::Graymap(scale(grid, factor)),
+            Image::Pixmap(grid) => Image::Pixmap(scale(grid, factor)),
+        }
+    }
+
+    /// Write the image in the binary variant of its format (P4, P5 or P6)
+    pub fn write<W>(&self, mut writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        match self {
+            Image::Bitmap(grid) => {
+                writeln!(writer, "P4\n{} {}", grid.width(), grid.height())?;
+                for row in grid.rows() {
+                    // Each row starts on a new byte, with the first pixel in the highest bit
+                    for pixels in row.chunks(8) {
+                        let byte = pixels
+                            .iter()
+                            .enumerate()
+                            .fold(0, |byte, (bit, black)| byte | u8::from(*black) << (7 - bit));
+                        writer.write_all(&[byte])?;
+                    }
+                }
+            }
+            Image::Graymap(grid) => {
+                writeln!(writer, "P5\n{} {}\n255", grid.width(), grid.height())?;
+                for row in grid.rows() {
+                    writer.write_all(row)?;
+                }
+            }
+            Image::Pixmap(grid) => {
+                writeln!(writer, "P6\n{} {}\n255", grid.width(), grid.height())?;
+                for Rgb(red, green, blue) in grid.iter() {
+                    writer.write_all(&[*red, *green, *blue])?;
+                }
+            }
+        }
+
+        Ok(writer.flush()?)
+    }
+
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if self.width() == 0 || self.height() == 0 {
+            return Err(anyhow!("Cannot save the empty image {}", path.display()));
+        }
+
+        let file = File::create(path).context(format!("Failed to create {}", path.display()))?;
+        self.write(BufWriter::new(file))
+            .context(format!("Failed to write {}", path.display()))
+    }
+}
+
+fn scale<T: Clone>(grid: &Grid<T>, factor: usize) -> Grid<T> {
+    let mut cells = Vec::with_capacity(grid.len() * factor * factor);
+    for row in grid.rows() {
+        for _ in 0..factor {
+            for cell in row {
+                cells.extend(std::iter::repeat_n(cell.clone(), factor));
+            }
+        }
+    }
+
+    Grid::from_vec(grid.width() * factor, cells).expect("every scaled row is the same width")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Image, Rgb};
+    use aoc_grid::Grid;
+
+    fn written(image: &Image) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_write_bitmap() {
+        // Rows of 10 pixels take two bytes each
+        let mut grid = Grid::new(10, 2, false);
+        grid[(0, 0)] = true;
+        grid[(0, 9)] = true;
+        grid[(1, 7)] = true;
+
+        let mut expected = b"P4\n10 2\n".to_vec();
+        expected.extend([0b1000_0000, 0b0100_0000, 0b0000_0001, 0]);
+        assert_eq!(written(&Image::Bitmap(grid)), expected);
+    }
+
+    #[test]
+    fn test_write_graymap_and_pixmap() {
+        let grid = Grid::from_rows(vec![vec![0, 128], vec![255, 1]]).unwrap();
+        let mut expected = b"P5\n2 2\n255\n".to_vec();
+        expected.extend([0, 128, 255, 1]);
+        assert_eq!(written(&Image::Graymap(grid)), expected);
+
+        let grid = Grid::from_rows(vec![vec![Rgb::RED, Rgb::gray(7)]]).unwrap();
+        let mut expected = b"P6\n2 1\n255\n".to_vec();
+        expected.extend([255, 0, 0, 7, 7, 7]);
+        assert_eq!(written(&Image::Pixmap(grid)), expected);
+    }
+
+    #[test]
+    fn test_scaled() {
+        let grid = Grid::from_rows(vec![vec![1, 2]]).unwrap();
+        let scaled = Image::Graymap(grid).scaled(2);
+        assert_eq!(
+            scaled,
+            Image::Graymap(Grid::from_rows(vec![vec![1, 1, 2, 2], vec![1, 1, 2, 2]]).unwrap())
+        );
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.extension(), "pgm");
+    }
+}
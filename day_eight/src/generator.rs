@@ -0,0 +1,71 @@
+//! Random notes on scrambled displays.
+use crate::DIGITS;
+use aoc_common::Rng;
+
+/// One display per line, each with its own random wiring.  The patterns and the letters within
+/// every pattern are shuffled too, like the real notes
+pub fn generate(rng: &mut Rng, displays: usize) -> String {
+    (0..displays)
+        .map(|_| {
+            let (patterns, outputs, _value) = random_display(rng);
+            format!("{} | {}\n", patterns.join(" "), outputs.join(" "))
+        })
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real input
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 200 * scale)
+}
+
+/// The ten patterns, the four outputs and the number the outputs show
+fn random_display(rng: &mut Rng) -> (Vec<String>, Vec<String>, usize) {
+    // wires[segment] is the wire that lights segment
+    let mut wires: Vec<char> = ('a'..='g').collect();
+    rng.shuffle(&mut wires);
+    let scrambled = |rng: &mut Rng, digit: usize| {
+        let mut letters: Vec<char> = DIGITS[digit]
+            .chars()
+            .map(|segment| wires[(segment as u8 - b'a') as usize])
+            .collect();
+        rng.shuffle(&mut letters);
+        letters.into_iter().collect::<String>()
+    };
+
+    let mut patterns: Vec<String> = (0..10).map(|digit| scrambled(rng, digit)).collect();
+    rng.shuffle(&mut patterns);
+    let digits: Vec<usize> = (0..4).map(|_| rng.range(0..10)).collect();
+    let outputs = digits.iter().map(|digit| scrambled(rng, *digit)).collect();
+
+    (
+        patterns,
+        outputs,
+        digits.iter().fold(0, |value, digit| value * 10 + digit),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::random_display;
+    use crate::{parse_str, part_one};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(8);
+        for _ in 0..200 {
+            let (patterns, outputs, value) = random_display(&mut rng);
+            let line = format!("{} | {}", patterns.join(" "), outputs.join(" "));
+            let entries = parse_str(&line).unwrap();
+
+            assert_eq!(entries[0].output_value().unwrap(), value);
+            let easy_digits = value
+                .to_string()
+                .chars()
+                .filter(|digit| "1478".contains(*digit))
+                .count();
+            // Leading zeros are digits too, but never 1, 4, 7 or 8
+            assert_eq!(part_one(&entries), easy_digits);
+        }
+    }
+}
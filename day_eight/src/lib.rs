@@ -0,0 +1,391 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+    const DAY: u8 = 8;
+    type Input = Vec<Entry>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+/// The segments that make up each digit on a working display, a to g
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// A set of wires (or segments), a to g, stored as one bit each
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Pattern(u8);
+
+impl Pattern {
+    /// None if letters holds anything other than a to g
+    pub fn from_letters(letters: &str) -> Option<Self> {
+        letters
+            .chars()
+            .try_fold(Pattern(0), |pattern, letter| match letter {
+                'a'..='g' => Some(Pattern(pattern.0 | 1 << (letter as u8 - b'a'))),
+                _ => None,
+            })
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every wire of other is also in self
+    pub fn contains(&self, other: Pattern) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn without(&self, other: Pattern) -> Pattern {
+        Pattern(self.0 & !other.0)
+    }
+
+    /// The wires as indexes from 0 (a) to 6 (g)
+    fn wires(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..7).filter(|wire| self.0 & 1 << wire != 0)
+    }
+
+    /// The only wire in the pattern, if it has exactly one
+    fn single_wire(&self) -> Option<usize> {
+        match self.len() {
+            1 => Some(self.0.trailing_zeros() as usize),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for wire in self.wires() {
+            write!(f, "{}", (b'a' + wire as u8) as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which segment each scrambled wire is really connected to, as deduced from the ten patterns of
+/// one display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireMapping {
+    /// segments[wire] is the segment that wire lights, both from 0 (a) to 6 (g)
+    segments: [usize; 7],
+}
+
+impl WireMapping {
+    /// Work out the mapping from the patterns of all ten digits, in any order.  Each digit is
+    /// picked out by its number of segments and which of the already known digits it contains,
+    /// and then each segment is the difference between two digits
+    pub fn deduce(patterns: &[Pattern; 10]) -> Result<Self> {
+        let with_len = |len: usize| patterns.iter().filter(move |pattern| pattern.len() == len);
+        let unique = |len: usize| -> Result<Pattern> {
+            let mut matching = with_len(len);
+            match (matching.next(), matching.next()) {
+                (Some(pattern), None) => Ok(*pattern),
+                _ => Err(anyhow!("Expected exactly one pattern with {} wires", len)),
+            }
+        };
+        let find = |len: usize, condition: &dyn Fn(&Pattern) -> bool, digit: u8| {
+            with_len(len)
+                .find(|pattern| condition(pattern))
+                .copied()
+                .ok_or_else(|| anyhow!("Could not pick out the pattern for {}", digit))
+        };
+
+        let one = unique(2)?;
+        let four = unique(4)?;
+        let seven = unique(3)?;
+        let eight = unique(7)?;
+        let nine = find(6, &|pattern| pattern.contains(four), 9)?;
+        let zero = find(6, &|pattern| *pattern != nine && pattern.contains(one), 0)?;
+        let six = find(6, &|pattern| *pattern != nine && *pattern != zero, 6)?;
+
+        let a = seven.without(one);
+        let c = eight.without(six);
+        let d = eight.without(zero);
+        let e = eight.without(nine);
+        let f = one.without(c);
+        let b = four.without(one).without(d);
+        let known = [a, b, c, d, e, f];
+        let g = known
+            .iter()
+            .fold(eight, |remaining, wire| remaining.without(*wire));
+
+        let mut segments = [0; 7];
+        for (segment, wire) in known.iter().chain([&g]).enumerate() {
+            let wire = wire
+                .single_wire()
+                .ok_or_else(|| anyhow!("Segment {} is not lit by exactly one wire", segment))?;
+            segments[wire] = segment;
+        }
+        let mapping = WireMapping { segments };
+
+        // Two, three and five were never used so make sure they agree with the wiring
+        let mut digits: Vec<_> = patterns
+            .iter()
+            .map(|pattern| mapping.decode(*pattern))
+            .collect::<Option<_>>()
+            .ok_or_else(|| anyhow!("Not every pattern is a digit under the deduced wiring"))?;
+        digits.sort_unstable();
+        if digits != (0..10).collect::<Vec<_>>() {
+            return Err(anyhow!("The patterns are not the ten different digits"));
+        }
+
+        Ok(mapping)
+    }
+
+    /// The segment that the scrambled wire lights, both as letters from a to g
+    pub fn segment(&self, wire: char) -> Option<char> {
+        let wire = (wire as usize).checked_sub('a' as usize)?;
+        let segment = self.segments.get(wire)?;
+        Some((b'a' + *segment as u8) as char)
+    }
+
+    /// The digit that lights up when the wires of pattern are on, if any
+    pub fn decode(&self, pattern: Pattern) -> Option<u8> {
+        let segments = pattern.wires().fold(Pattern(0), |segments, wire| {
+            Pattern(segments.0 | 1 << self.segments[wire])
+        });
+
+        DIGITS
+            .iter()
+            .position(|digit| Pattern::from_letters(digit) == Some(segments))
+            .map(|digit| digit as u8)
+    }
+}
+
+/// Every wire and the segment it lights, e.g. `a->d b->e c->a ...`
+impl fmt::Display for WireMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (wire, segment) in self.segments.iter().enumerate() {
+            if wire > 0 {
+                write!(f, " ")?;
+            }
+            write!(
+                f,
+                "{}->{}",
+                (b'a' + wire as u8) as char,
+                (b'a' + *segment as u8) as char
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// One line of notes: the pattern of every digit and then the four digits being displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub patterns: [Pattern; 10],
+    pub outputs: [Pattern; 4],
+}
+
+impl Entry {
+    pub fn mapping(&self) -> Result<WireMapping> {
+        WireMapping::deduce(&self.patterns)
+    }
+
+    /// The four output digits read as one number
+    pub fn output_value(&self) -> Result<usize> {
+        let mapping = self.mapping()?;
+        self.outputs.iter().try_fold(0, |value, output| {
+            let digit = mapping
+                .decode(*output)
+                .ok_or_else(|| anyhow!("{} is not a digit under {}", output, mapping))?;
+            Ok(value * 10 + usize::from(digit))
+        })
+    }
+}
+
+/// 1, 4, 7 and 8 are the only digits with their number of segments
+pub fn part_one(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| entry.outputs.iter())
+        .filter(|output| matches!(output.len(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+pub fn part_two(entries: &[Entry]) -> Result<usize> {
+    entries.iter().map(Entry::output_value).sum()
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Entry>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Entry>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Entry>> {
+    parse_lines(input.lines())
+}
+
+/// Ten patterns, a `|` and four outputs per line.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Entry>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut entries = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (patterns, outputs) = line.split_once('|').ok_or_else(|| {
+            ParseError::invalid(line_index, 0, line, "ten patterns | four outputs")
+        })?;
+        entries.push(Entry {
+            patterns: parse_patterns(line_index, 0, patterns)?,
+            outputs: parse_patterns(line_index, patterns.len() + 1, outputs)?,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Exactly N space separated patterns.  column_index is where patterns starts in the line
+fn parse_patterns<const N: usize>(
+    line_index: usize,
+    column_index: usize,
+    patterns: &str,
+) -> ParseResult<[Pattern; N]> {
+    let mut parsed = [Pattern::default(); N];
+    let mut count = 0;
+    let mut pattern_column = column_index;
+    for letters in patterns.split(' ') {
+        if !letters.is_empty() {
+            let pattern = Pattern::from_letters(letters)
+                .filter(|pattern| pattern.len() == letters.len())
+                .ok_or_else(|| {
+                    ParseError::invalid(
+                        line_index,
+                        pattern_column,
+                        letters,
+                        "a pattern of different letters from a to g",
+                    )
+                })?;
+            if count < N {
+                parsed[count] = pattern;
+            }
+            count += 1;
+        }
+        pattern_column += letters.len() + 1;
+    }
+
+    if count != N {
+        return Err(ParseError::invalid(
+            line_index,
+            column_index,
+            patterns.trim(),
+            format!("{} patterns", N),
+        ));
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, parse_str, part_one, part_two, Pattern};
+
+    const SMALL_EXAMPLE: &str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | \
+                                 cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn test_parse_input() {
+        let entries = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(entries.len(), 10);
+        assert_eq!(entries[0].patterns[0], Pattern::from_letters("be").unwrap());
+        assert_eq!(entries[0].outputs[3].to_string(), "bceg");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("ab | cd").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected 10 patterns, found \"ab\""
+        );
+        let error = parse_str(&SMALL_EXAMPLE.replace("dab", "dah")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 27: expected a pattern of different letters from a to g, found \"dah\""
+        );
+        assert!(parse_str(&SMALL_EXAMPLE.replace("dab", "daa")).is_err());
+        assert!(parse_str(&SMALL_EXAMPLE.replace(" | ", " ")).is_err());
+    }
+
+    #[test]
+    fn test_mapping() {
+        let entry = &parse_str(SMALL_EXAMPLE).unwrap()[0];
+        let mapping = entry.mapping().unwrap();
+        assert_eq!(mapping.to_string(), "a->c b->f c->g d->a e->b f->d g->e");
+        assert_eq!(mapping.segment('d'), Some('a'));
+        assert_eq!(mapping.segment('z'), None);
+        assert_eq!(entry.output_value().unwrap(), 5353);
+    }
+
+    #[test]
+    fn test_output_values() {
+        let entries = parse_input("src/example_input.txt").unwrap();
+        let values: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.output_value().unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![8394, 9781, 1197, 9361, 4873, 8418, 4548, 1625, 8717, 4315]
+        );
+
+        // Two patterns for one means there is no eight
+        let broken = parse_str(&SMALL_EXAMPLE.replace("acedgfb", "ef")).unwrap();
+        assert!(broken[0].mapping().is_err());
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let entries = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_one(&entries), 26);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let entries = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_two(&entries).unwrap(), 61229);
+    }
+}
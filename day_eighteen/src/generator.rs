@@ -0,0 +1,54 @@
+//! Random snailfish homework.
+use crate::SnailNumber;
+use aoc_common::Rng;
+
+/// numbers lines of already reduced snailfish numbers, each of them a pair like the real ones
+pub fn generate(rng: &mut Rng, numbers: usize) -> String {
+    (0..numbers)
+        .map(|_| {
+            let levels = SnailNumber::EXPLODE_DEPTH - 1;
+            let number =
+                SnailNumber::pair(random_number(rng, levels, 9), random_number(rng, levels, 9));
+            format!("{}\n", number)
+        })
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real homework
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 100 * scale)
+}
+
+/// A number with pairs nested at most levels deep and regular numbers up to max_value
+pub fn random_number(rng: &mut Rng, levels: usize, max_value: u32) -> SnailNumber {
+    if levels == 0 || rng.chance(0.3) {
+        SnailNumber::Regular(rng.range(0..max_value as usize + 1) as u32)
+    } else {
+        SnailNumber::pair(
+            random_number(rng, levels - 1, max_value),
+            random_number(rng, levels - 1, max_value),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(18);
+        let input = generate(&mut rng, 20);
+        let numbers = parse_str(&input).unwrap();
+        assert_eq!(numbers.len(), 20);
+        assert!(numbers
+            .iter()
+            .all(|number| number.is_reduced() && number.depth().is_some()));
+
+        // Adding never makes anything smaller, so the best pair beats the first two alone
+        let first_two = part_one(&numbers[..2]).unwrap();
+        assert!(part_two(&numbers).unwrap() >= first_two);
+    }
+}
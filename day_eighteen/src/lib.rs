@@ -0,0 +1,503 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayEighteen;
+
+impl Solution for DayEighteen {
+    const DAY: u8 = 18;
+    type Input = Vec<SnailNumber>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+/// Either a regular number or a pair of snailfish numbers, written `[left,right]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnailNumber {
+    Regular(u32),
+    Pair(Box<SnailNumber>, Box<SnailNumber>),
+}
+
+impl SnailNumber {
+    /// Pairs nested inside this many pairs explode
+    pub const EXPLODE_DEPTH: usize = 4;
+    /// Regular numbers at least this big split
+    pub const SPLIT_AT: u32 = 10;
+    /// The deepest a parsed pair can be nested, which keeps the recursion on numbers shallow
+    pub const MAX_DEPTH: usize = 16;
+    /// The biggest regular number the parser accepts, which keeps magnitudes within 64 bits
+    pub const MAX_REGULAR: u32 = 1_000_000;
+
+    pub fn pair(left: SnailNumber, right: SnailNumber) -> Self {
+        SnailNumber::Pair(Box::new(left), Box::new(right))
+    }
+
+    /// The number of pairs around the most deeply nested pair, so 0 for a plain pair and
+    /// None for a regular number
+    pub fn depth(&self) -> Option<usize> {
+        match self {
+            SnailNumber::Regular(_) => None,
+            SnailNumber::Pair(left, right) => Some(
+                left.depth()
+                    .max(right.depth())
+                    .map_or(0, |deepest| deepest + 1),
+            ),
+        }
+    }
+
+    /// Whether neither an explode nor a split applies
+    pub fn is_reduced(&self) -> bool {
+        self.depth().is_none_or(|depth| depth < Self::EXPLODE_DEPTH) && !self.has_split()
+    }
+
+    fn has_split(&self) -> bool {
+        match self {
+            SnailNumber::Regular(value) => *value >= Self::SPLIT_AT,
+            SnailNumber::Pair(left, right) => left.has_split() || right.has_split(),
+        }
+    }
+
+    /// Explode the leftmost pair of two regular numbers that is nested too deeply.  Returns
+    /// whether there was one, and fails if a regular number grows past 32 bits
+    pub fn explode(&mut self) -> Result<bool> {
+        Ok(self.explode_at(0)?.is_some())
+    }
+
+    /// Returns the values that still have to be added to the regular numbers either side of the
+    /// exploded pair, if one exploded
+    fn explode_at(&mut self, depth: usize) -> Result<Option<(Option<u32>, Option<u32>)>> {
+        let SnailNumber::Pair(left, right) = self else {
+            return Ok(None);
+        };
+
+        if depth >= Self::EXPLODE_DEPTH {
+            if let (SnailNumber::Regular(left), SnailNumber::Regular(right)) = (&**left, &**right) {
+                let carry = (Some(*left), Some(*right));
+                *self = SnailNumber::Regular(0);
+                return Ok(Some(carry));
+            }
+        }
+
+        if let Some((carry_left, carry_right)) = left.explode_at(depth + 1)? {
+            if let Some(value) = carry_right {
+                right.add_to_leftmost(value)?;
+            }
+            return Ok(Some((carry_left, None)));
+        }
+        if let Some((carry_left, carry_right)) = right.explode_at(depth + 1)? {
+            if let Some(value) = carry_left {
+                left.add_to_rightmost(value)?;
+            }
+            return Ok(Some((None, carry_right)));
+        }
+
+        Ok(None)
+    }
+
+    fn add_to_leftmost(&mut self, value: u32) -> Result<()> {
+        match self {
+            SnailNumber::Regular(regular) => Self::add_to_regular(regular, value),
+            SnailNumber::Pair(left, _) => left.add_to_leftmost(value),
+        }
+    }
+
+    fn add_to_rightmost(&mut self, value: u32) -> Result<()> {
+        match self {
+            SnailNumber::Regular(regular) => Self::add_to_regular(regular, value),
+            SnailNumber::Pair(_, right) => right.add_to_rightmost(value),
+        }
+    }
+
+    fn add_to_regular(regular: &mut u32, value: u32) -> Result<()> {
+        *regular = regular.checked_add(value).ok_or_else(|| {
+            anyhow!(
+                "Exploding adds {} to {}, which does not fit in 32 bits",
+                value,
+                regular
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Split the leftmost regular number that is too big into a pair of its halves, rounding
+    /// the left down and the right up.  Returns whether there was one
+    pub fn split(&mut self) -> bool {
+        match self {
+            SnailNumber::Regular(value) if *value >= Self::SPLIT_AT => {
+                *self = SnailNumber::pair(
+                    SnailNumber::Regular(*value / 2),
+                    SnailNumber::Regular(value.div_ceil(2)),
+                );
+                true
+            }
+            SnailNumber::Regular(_) => false,
+            SnailNumber::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+
+    /// Explode until nothing explodes, then split once, and repeat until neither applies
+    pub fn reduce(&mut self) -> Result<()> {
+        while self.explode()? || self.split() {}
+        Ok(())
+    }
+
+    /// The pair of both numbers, reduced
+    pub fn checked_add(self, other: SnailNumber) -> Result<SnailNumber> {
+        let mut sum = SnailNumber::pair(self, other);
+        sum.reduce()?;
+        Ok(sum)
+    }
+
+    /// Three times the left plus twice the right, all the way down
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            SnailNumber::Regular(value) => *value as u64,
+            SnailNumber::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+impl fmt::Display for SnailNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnailNumber::Regular(value) => write!(f, "{}", value),
+            SnailNumber::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+/// The magnitude of the whole list added up in order
+pub fn part_one(numbers: &[SnailNumber]) -> Result<u64> {
+    let (first, rest) = numbers
+        .split_first()
+        .ok_or_else(|| anyhow!("There are no numbers to add"))?;
+    let sum = rest
+        .iter()
+        .try_fold(first.clone(), |sum, number| sum.checked_add(number.clone()))?;
+    Ok(sum.magnitude())
+}
+
+/// The largest magnitude of any two different numbers added together, in either order
+pub fn part_two(numbers: &[SnailNumber]) -> Result<u64> {
+    let mut largest = None;
+    for first in 0..numbers.len() {
+        for second in (0..numbers.len()).filter(|second| *second != first) {
+            let sum = numbers[first]
+                .clone()
+                .checked_add(numbers[second].clone())?;
+            largest = largest.max(Some(sum.magnitude()));
+        }
+    }
+    largest.ok_or_else(|| anyhow!("At least two numbers are needed to add a pair"))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<SnailNumber>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<SnailNumber>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<SnailNumber>> {
+    parse_lines(input.lines())
+}
+
+/// One snailfish number per line.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<SnailNumber>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut numbers = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+        numbers.push(parse_number(line_index, line)?);
+    }
+
+    Ok(numbers)
+}
+
+/// The whole of line as one snailfish number
+pub fn parse_number(line_index: usize, line: &str) -> ParseResult<SnailNumber> {
+    let mut parser = Parser {
+        line_index,
+        line,
+        position: 0,
+        depth: 0,
+    };
+    let number = parser.number()?;
+    if parser.position < line.len() {
+        return Err(parser.invalid("the end of the number"));
+    }
+
+    Ok(number)
+}
+
+/// Reads one snailfish number from left to right
+struct Parser<'a> {
+    line_index: usize,
+    line: &'a str,
+    position: usize,
+    /// The number of pairs around the current position
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn number(&mut self) -> ParseResult<SnailNumber> {
+        match self.line.as_bytes().get(self.position) {
+            Some(b'[') if self.depth > SnailNumber::MAX_DEPTH => Err(self.invalid(format!(
+                "a regular number, since pairs nest at most {} deep",
+                SnailNumber::MAX_DEPTH
+            ))),
+            Some(b'[') => {
+                self.position += 1;
+                self.depth += 1;
+                let left = self.number()?;
+                self.expect(b',')?;
+                let right = self.number()?;
+                self.expect(b']')?;
+                self.depth -= 1;
+                Ok(SnailNumber::pair(left, right))
+            }
+            Some(byte) if byte.is_ascii_digit() => {
+                let digits = self.line[self.position..]
+                    .bytes()
+                    .take_while(u8::is_ascii_digit)
+                    .count();
+                let value = self.line[self.position..self.position + digits]
+                    .parse()
+                    .ok()
+                    .filter(|value| *value <= SnailNumber::MAX_REGULAR)
+                    .ok_or_else(|| {
+                        self.invalid(format!(
+                            "a regular number up to {}",
+                            SnailNumber::MAX_REGULAR
+                        ))
+                    })?;
+                self.position += digits;
+                Ok(SnailNumber::Regular(value))
+            }
+            _ => Err(self.invalid("a regular number or a pair")),
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> ParseResult<()> {
+        if self.line.as_bytes().get(self.position) == Some(&byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.invalid(format!("'{}'", byte as char)))
+        }
+    }
+
+    /// expected was missing at the current position
+    fn invalid<E>(&self, expected: E) -> ParseError
+    where
+        E: Into<String>,
+    {
+        ParseError::invalid(
+            self.line_index,
+            self.position,
+            &self.line[self.position..],
+            expected,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::random_number;
+    use crate::{parse_input, parse_number, parse_str, part_one, part_two, SnailNumber};
+    use aoc_common::Rng;
+
+    fn number(text: &str) -> SnailNumber {
+        parse_number(0, text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let numbers = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(numbers.len(), 10);
+        assert_eq!(
+            numbers[7],
+            SnailNumber::pair(
+                SnailNumber::pair(SnailNumber::Regular(9), SnailNumber::Regular(3)),
+                number("[[9,9],[6,[4,9]]]")
+            )
+        );
+        assert_eq!(numbers[0].depth(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("[1,2]\n[[1,2],3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 9: expected ']', found \"\""
+        );
+        let error = parse_str("[1,x]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected a regular number or a pair, found \"x]\""
+        );
+        assert!(parse_str("[1,2]]").is_err());
+        assert!(parse_str("[1;2]").is_err());
+        assert!(parse_str("[1,99999999999]").is_err());
+
+        let error = parse_str("[1,1000001]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected a regular number up to 1000000, found \"1000001]\""
+        );
+        assert!(parse_str("[1,4294967295]").is_err());
+
+        // As deep as pairs go, and then one deeper
+        let deepest = format!("{}1,2]{}", "[".repeat(17), ",3]".repeat(16));
+        assert_eq!(number(&deepest).depth(), Some(SnailNumber::MAX_DEPTH));
+        let too_deep = format!("[{},4]", deepest);
+        assert!(parse_str(&too_deep).is_err());
+        let error = parse_str(&"[".repeat(100_000)).unwrap_err();
+        assert!(
+            error.to_string().starts_with("line 1, column 18: "),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let mut number = number(before);
+            assert!(number.explode().unwrap());
+            assert_eq!(number.to_string(), after);
+        }
+        assert!(!number("[[[[0,9],2],3],4]").explode().unwrap());
+
+        // The parser keeps regular numbers small, but built by hand they can still overflow
+        let regular = SnailNumber::Regular;
+        let mut number = SnailNumber::pair(
+            SnailNumber::pair(
+                SnailNumber::pair(
+                    SnailNumber::pair(SnailNumber::pair(regular(1), regular(1)), regular(u32::MAX)),
+                    regular(2),
+                ),
+                regular(3),
+            ),
+            regular(4),
+        );
+        assert!(number.explode().is_err());
+    }
+
+    #[test]
+    fn test_split() {
+        let mut number = number("[[[[0,7],4],[15,[0,13]]],[1,1]]");
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+        assert!(number.split());
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+        assert!(!number.is_reduced());
+    }
+
+    #[test]
+    fn test_add() {
+        let sum = number("[[[[4,3],4],4],[7,[[8,4],9]]]")
+            .checked_add(number("[1,1]"))
+            .unwrap();
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        assert!(sum.is_reduced());
+
+        let numbers = parse_input("src/example_input.txt").unwrap();
+        let sum = numbers
+            .into_iter()
+            .reduce(|sum, number| sum.checked_add(number).unwrap());
+        assert_eq!(
+            sum.unwrap().to_string(),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(number("[[1,2],[[3,4],5]]").magnitude(), 143);
+        assert_eq!(
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+            3488
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let mut rng = Rng::new(18);
+        for _ in 0..500 {
+            // Deeper and bigger than reduced numbers get, so the parser sees those too
+            let original = random_number(&mut rng, 7, 150);
+            assert_eq!(number(&original.to_string()), original);
+        }
+    }
+
+    #[test]
+    fn test_sums_are_reduced() {
+        let mut rng = Rng::new(81);
+        for _ in 0..200 {
+            let sum = random_number(&mut rng, 4, 9)
+                .checked_add(random_number(&mut rng, 4, 9))
+                .unwrap();
+            assert!(sum.is_reduced(), "{}", sum);
+        }
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let numbers = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_one(&numbers).unwrap(), 4140);
+        assert!(part_one(&[]).is_err());
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let numbers = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_two(&numbers).unwrap(), 3993);
+        assert!(part_two(&numbers[..1]).is_err());
+    }
+}
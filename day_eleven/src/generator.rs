@@ -0,0 +1,85 @@
+//! Random octopus grids.
+use crate::{part_one_step, Octopus};
+use aoc_common::Rng;
+use aoc_grid::Grid;
+
+/// Grids that synchronize at all do so within a few hundred steps.  Ones that have not after this
+/// many never seem to
+const MAX_STEPS: usize = 1000;
+
+/// A width by height grid of energy levels from 0 to 9.  A fraction noise of the octopuses get a
+/// random level and the rest all share one level.
+///
+/// Part two only finishes once every octopus flashes in the same step, which not every grid
+/// does, so grids are drawn again until one synchronizes.  Large grids that are mostly noise
+/// almost never synchronize so keep noise low for them
+pub fn generate(rng: &mut Rng, width: usize, height: usize, noise: f64) -> String {
+    loop {
+        let grid = random_grid(rng, width, height, noise);
+        if synchronizes_within(&grid, MAX_STEPS).is_some() {
+            return grid
+                .rows()
+                .map(|row| {
+                    let mut line: String = row.iter().map(|level| level.to_string()).collect();
+                    line.push('\n');
+                    line
+                })
+                .collect();
+        }
+    }
+}
+
+/// A scale of 1 is a fully random 10 by 10 grid like the real input.  Larger grids are mostly a
+/// single level so that they still synchronize
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    let side = 10 * scale.isqrt().max(1);
+    let noise = if scale <= 1 { 1.0 } else { 0.2 };
+    generate(rng, side, side, noise)
+}
+
+fn random_grid(rng: &mut Rng, width: usize, height: usize, noise: f64) -> Grid<usize> {
+    let shared_level = rng.range(0..10);
+    let mut grid = Grid::new(width, height, shared_level);
+    for level in grid.iter_mut() {
+        if rng.chance(noise) {
+            *level = rng.range(0..10);
+        }
+    }
+    grid
+}
+
+/// The first step in which every octopus flashes, if there is one within max_steps
+fn synchronizes_within(grid: &Grid<usize>, max_steps: usize) -> Option<usize> {
+    let mut octopuses = grid.map(|level| Octopus::Charging(*level));
+    for step in 1..=max_steps {
+        let (flashed, next) = part_one_step(octopuses);
+        if flashed == grid.len() {
+            return Some(step);
+        }
+        octopuses = next;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{generate, MAX_STEPS};
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(11);
+        for (side, noise) in [(10, 1.0), (30, 0.2)] {
+            let grid = parse_str(&generate(&mut rng, side, side, noise)).unwrap();
+            assert_eq!((grid.width(), grid.height()), (side, side));
+
+            let synchronized = part_two(&grid);
+            assert!(synchronized <= MAX_STEPS);
+            // Once they are in sync every octopus flashes together every tenth step
+            if synchronized <= 100 {
+                assert!(part_one(&grid) >= ((100 - synchronized) / 10 + 1) * grid.len());
+            }
+        }
+    }
+}
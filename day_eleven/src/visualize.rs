@@ -0,0 +1,63 @@
+//! Images of the octopuses charging and flashing.
+use crate::{parse_str, part_one_step, Octopus};
+use anyhow::Result;
+use aoc_grid::Grid;
+use aoc_image::{ColorMap, Frames, Image, Rgb};
+
+/// Write a frame of the starting energy levels and then one for each step, until every octopus
+/// flashes in the same step or steps have been taken
+pub fn flashes(input: &Grid<usize>, steps: usize, frames: &mut Frames) -> Result<()> {
+    let mut oc_grid = input.map(|charge| Octopus::Charging(*charge));
+    frames.push(&render(&oc_grid))?;
+
+    for _step in 0..steps {
+        let (flash_count, next_oc_grid) = part_one_step(oc_grid);
+        oc_grid = next_oc_grid;
+        frames.push(&render(&oc_grid))?;
+        if flash_count == oc_grid.len() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse input and write the frames of [`flashes`] for up to 1000 steps
+pub fn visualize(input: &str, frames: &mut Frames) -> Result<()> {
+    flashes(&parse_str(input)?, 1000, frames)
+}
+
+/// Octopuses that just flashed are white and the rest glow brighter the more energy they have
+fn render(oc_grid: &Grid<Octopus>) -> Image {
+    Image::Pixmap(oc_grid.map(|octopus| match octopus {
+        Octopus::Charging(0) | Octopus::Flashed => Rgb::WHITE,
+        // Stop short of the white end of the map so a full charge does not look like a flash
+        Octopus::Charging(level) => ColorMap::Heat.color_between(*level as f64, 0.0, 12.0),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_str;
+    use crate::visualize::flashes;
+    use aoc_image::{ColorMap, Frames, Rgb};
+
+    #[test]
+    fn test_flashes() {
+        let grid = parse_str("11111\n19991\n19191\n19991\n11111\n").unwrap();
+        let directory =
+            std::env::temp_dir().join(format!("day_eleven_flashes_{}", std::process::id()));
+        let mut frames = Frames::create(&directory, "octopuses").unwrap();
+        flashes(&grid, 2, &mut frames).unwrap();
+        assert_eq!(frames.len(), 3);
+
+        // After the first step the ring of nines has flashed and the corners are at 3
+        let first_step = std::fs::read(directory.join("octopuses_00001.ppm")).unwrap();
+        let pixels = &first_step[b"P6\n5 5\n255\n".len()..];
+        let Rgb(red, green, blue) = ColorMap::Heat.color_between(3.0, 0.0, 12.0);
+        assert_eq!(&pixels[..3], &[red, green, blue]);
+        assert_eq!(&pixels[6 * 3..7 * 3], &[255, 255, 255]);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+//! Random cave risk maps.
+use aoc_common::Rng;
+
+/// A width by height map of risk levels from 1 to 9
+pub fn generate(rng: &mut Rng, width: usize, height: usize) -> String {
+    (0..height)
+        .map(|_| {
+            let mut line: String = (0..width).map(|_| rng.range(1..10).to_string()).collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real map.  Both sides grow so the area grows with scale
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    let side = (100 * 100 * scale).isqrt();
+    generate(rng, side, side)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{lowest_total_risk, parse_str, tile_vector};
+    use aoc_common::Rng;
+    use aoc_grid::Grid;
+
+    /// Keep relaxing every position until nothing gets any cheaper.  Slow but hard to get wrong
+    fn relaxed_risk(risk_map: &Grid<usize>) -> usize {
+        let mut total: Grid<usize> = risk_map.map(|_| usize::MAX);
+        total[(0, 0)] = 0;
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for position in risk_map.positions() {
+                let cheapest_neighbor = risk_map
+                    .neighbors4(position)
+                    .map(|neighbor| total[neighbor])
+                    .min()
+                    .unwrap_or(usize::MAX);
+                let through_neighbor = cheapest_neighbor.saturating_add(risk_map[position]);
+                if through_neighbor < total[position] {
+                    total[position] = through_neighbor;
+                    changed = true;
+                }
+            }
+        }
+
+        total[(risk_map.height() - 1, risk_map.width() - 1)]
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(15);
+        for (width, height) in [(1, 1), (7, 3), (12, 12)] {
+            let risk_map = parse_str(&generate(&mut rng, width, height)).unwrap();
+            assert_eq!((risk_map.width(), risk_map.height()), (width, height));
+
+            assert_eq!(
+                lowest_total_risk(&risk_map).unwrap(),
+                relaxed_risk(&risk_map)
+            );
+            let tiled = tile_vector(&risk_map, 5, 5);
+            assert_eq!(lowest_total_risk(&tiled).unwrap(), relaxed_risk(&tiled));
+        }
+    }
+}
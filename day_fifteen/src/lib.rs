@@ -0,0 +1,251 @@
+pub mod generator;
+pub mod visualize;
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_grid::{Grid, Position};
+use petgraph::{graph::NodeIndex, visit::EdgeRef, Graph, Undirected};
+
+pub struct DayFifteen;
+
+impl Solution for DayFifteen {
+    const DAY: u8 = 15;
+    type Input = Grid<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        lowest_total_risk(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        lowest_total_risk(&tile_vector(input, 5, 5))
+    }
+}
+
+pub fn part_one<P>(input_path: P) -> Result<usize>
+where
+    P: AsRef<Path>,
+{
+    lowest_total_risk(&parse_input(input_path)?)
+}
+
+pub fn part_two<P>(input_path: P) -> Result<usize>
+where
+    P: AsRef<Path>,
+{
+    lowest_total_risk(&tile_vector(&parse_input(input_path)?, 5, 5))
+}
+
+/// Return the lowest total risk of any path from the top left to the bottom right of risk_map
+fn lowest_total_risk(risk_map: &Grid<usize>) -> Result<usize> {
+    lowest_risk_path(risk_map).map(|(risk, _path)| risk)
+}
+
+/// The lowest total risk from the top left to the bottom right of risk_map and every position
+/// along the way, both ends included
+fn lowest_risk_path(risk_map: &Grid<usize>) -> Result<(usize, Vec<Position>)> {
+    if risk_map.is_empty() {
+        return Err(anyhow!("An empty risk map has no path through it"));
+    }
+    let (graph, start, end) = create_undirected_with_goals(risk_map);
+
+    let x = petgraph::algo::astar::astar(
+        &graph,
+        start,
+        |finish| finish == end,
+        |edge| {
+            *graph
+                .node_weight(edge.target())
+                .expect("node weight exists")
+        },
+        |node| {
+            log::trace!("queueing node {}", node.index());
+            0
+        },
+    );
+    match x {
+        Some((found_weight, path)) => {
+            log::debug!("lowest risk path visits {} positions", path.len());
+            // Nodes were added in the same order as the cells of risk_map
+            let positions = path
+                .into_iter()
+                .map(|node| {
+                    (
+                        node.index() / risk_map.width(),
+                        node.index() % risk_map.width(),
+                    )
+                })
+                .collect();
+            Ok((found_weight, positions))
+        }
+        None => Err(anyhow!(
+            "There is no path to the bottom right of the risk map"
+        )),
+    }
+}
+
+pub fn create_undirected_with_goals(
+    risk_map: &Grid<usize>,
+) -> (
+    Graph<usize, usize, Undirected, usize>,
+    NodeIndex<usize>,
+    NodeIndex<usize>,
+) {
+    let mut working_graph: Graph<usize, usize, Undirected, usize> =
+        petgraph::Graph::with_capacity(88, 88);
+
+    // Add all of the nodes to the graph so that I don't have to worry when adding the edges
+    let node_indecies: Grid<NodeIndex<usize>> = risk_map.map(|risk| working_graph.add_node(*risk));
+
+    for ((row_index, col_index), current_node_index) in node_indecies.indexed_iter() {
+        if col_index > 0 {
+            working_graph.add_edge(
+                *current_node_index,
+                node_indecies[(row_index, col_index - 1)],
+                0,
+            );
+        }
+        if row_index > 0 {
+            working_graph.add_edge(
+                *current_node_index,
+                node_indecies[(row_index - 1, col_index)],
+                0,
+            );
+        }
+    }
+
+    (
+        working_graph,
+        *node_indecies.iter().next().expect("start index exists"),
+        *node_indecies.iter().last().expect("goal index exists"),
+    )
+}
+
+/// Collect the risk map located at input_path}
+pub fn parse_input<P>(input_path: P) -> ParseResult<Grid<usize>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(mut reader: R) -> ParseResult<Grid<usize>>
+where
+    R: BufRead,
+{
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    parse_str(&input)
+}
+
+/// A grid of single digit risk levels, which must have at least one row
+pub fn parse_str(input: &str) -> ParseResult<Grid<usize>> {
+    let risk_map = aoc_grid::parse_digits(input)?;
+    if risk_map.is_empty() {
+        return Err(ParseError::unexpected_end(
+            input.lines().count(),
+            "a row of risk levels",
+        ));
+    }
+
+    Ok(risk_map)
+}
+
+/// row_countr and col_countr will be total counts.  That means that 1, 1 is the identity
+fn tile_vector(risk_map: &Grid<usize>, row_count: usize, col_count: usize) -> Grid<usize> {
+    let mut tiled = Grid::new(
+        risk_map.width() * col_count,
+        risk_map.height() * row_count,
+        0,
+    );
+
+    for ((row, col), risk) in tiled.indexed_iter_mut() {
+        let up_down_modifyer = row / risk_map.height();
+        let left_right_modifyer = col / risk_map.width();
+        let x = risk_map[(row % risk_map.height(), col % risk_map.width())];
+        *risk = (((x + left_right_modifyer + up_down_modifyer) - 1) % 9) + 1;
+    }
+
+    tiled
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lowest_total_risk, parse_input, parse_str, part_one, part_two, tile_vector};
+    use aoc_grid::Grid;
+
+    #[test]
+    fn test_parse_input() {
+        let expected_result = Grid::from_rows(vec![
+            vec![1, 1, 6, 3, 7, 5, 1, 7, 4, 2],
+            vec![1, 3, 8, 1, 3, 7, 3, 6, 7, 2],
+            vec![2, 1, 3, 6, 5, 1, 1, 3, 2, 8],
+            vec![3, 6, 9, 4, 9, 3, 1, 5, 6, 9],
+            vec![7, 4, 6, 3, 4, 1, 7, 1, 1, 1],
+            vec![1, 3, 1, 9, 1, 2, 8, 1, 3, 7],
+            vec![1, 3, 5, 9, 9, 1, 2, 4, 2, 1],
+            vec![3, 1, 2, 5, 4, 2, 1, 6, 3, 9],
+            vec![1, 2, 9, 3, 1, 3, 8, 5, 2, 1],
+            vec![2, 3, 1, 1, 9, 4, 4, 5, 8, 1],
+        ])
+        .unwrap();
+
+        let actual = parse_input("src/test_input.txt");
+
+        assert_eq!(actual.expect("Parse input return Ok"), expected_result);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert!(parse_str("").is_err());
+        assert!(parse_str("\n\n").is_err());
+        assert!(lowest_total_risk(&Grid::new(0, 0, 1)).is_err());
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let expected = 40;
+
+        let actual = part_one("src/test_input.txt");
+        match actual {
+            Ok(actual) => assert_eq!(actual, expected),
+            Err(actual) => panic!("{}", actual),
+        }
+    }
+
+    #[test]
+    fn test_tile_vector() {
+        let expected = parse_input("src/tiled_test_input.txt").expect("parse_input test passes");
+        let actual = tile_vector(
+            &parse_input("src/test_input.txt").expect("parse_input test passes"),
+            5,
+            5,
+        );
+        assert_eq!(actual, expected,"")
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let expected = 315;
+
+        let actual = part_two("src/test_input.txt");
+        match actual {
+            Ok(actual) => assert_eq!(actual, expected),
+            Err(actual) => panic!("{}", actual),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Images of the lowest risk path through the cave.
+use crate::{lowest_risk_path, parse_str};
+use anyhow::Result;
+use aoc_grid::{Grid, Position};
+use aoc_image::{ColorMap, Frames, Image, Rgb};
+
+/// Write a frame of the bare risk map and then follow the lowest risk path across it,
+/// positions_per_frame positions at a time
+pub fn risk_path(
+    risk_map: &Grid<usize>,
+    positions_per_frame: usize,
+    frames: &mut Frames,
+) -> Result<()> {
+    let (_risk, path) = lowest_risk_path(risk_map)?;
+    frames.push(&render(risk_map, &[]))?;
+
+    let mut revealed = 0;
+    for positions in path.chunks(positions_per_frame.max(1)) {
+        revealed += positions.len();
+        frames.push(&render(risk_map, &path[..revealed]))?;
+    }
+
+    Ok(())
+}
+
+/// Parse input and write the frames of [`risk_path`] across the part one map, in about 100
+/// steps
+pub fn visualize(input: &str, frames: &mut Frames) -> Result<()> {
+    let risk_map = parse_str(input)?;
+    let positions_per_frame = (risk_map.width() + risk_map.height()).div_ceil(100);
+    risk_path(&risk_map, positions_per_frame, frames)
+}
+
+/// Low risks are dark and high ones bright, with the path in red
+fn render(risk_map: &Grid<usize>, path: &[Position]) -> Image {
+    let mut pixels = risk_map.map(|risk| ColorMap::Viridis.color_between(*risk as f64, 1.0, 9.0));
+    for position in path {
+        pixels[*position] = Rgb::RED;
+    }
+
+    Image::Pixmap(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_input;
+    use crate::visualize::risk_path;
+    use aoc_image::Frames;
+
+    #[test]
+    fn test_risk_path() {
+        let risk_map = parse_input("src/test_input.txt").unwrap();
+        let directory =
+            std::env::temp_dir().join(format!("day_fifteen_risk_path_{}", std::process::id()));
+        let mut frames = Frames::create(&directory, "path").unwrap();
+        // The path across a 10 by 10 map visits 19 positions
+        risk_path(&risk_map, 5, &mut frames).unwrap();
+        assert_eq!(frames.len(), 5);
+
+        let last = std::fs::read(directory.join("path_00004.ppm")).unwrap();
+        let pixels = &last[b"P6\n10 10\n255\n".len()..];
+        let red = pixels
+            .chunks(3)
+            .filter(|pixel| pixel == &[255, 0, 0])
+            .count();
+        assert_eq!(red, 19);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,74 @@
+//! Random vent lines.
+use aoc_common::Rng;
+
+/// lines segments with both ends from 0 to extent - 1.  About a third each are horizontal,
+/// vertical and diagonal
+pub fn generate(rng: &mut Rng, lines: usize, extent: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..lines {
+        let (x1, y1) = (rng.range(0..extent), rng.range(0..extent));
+        let (x2, y2) = match rng.range(0..3) {
+            0 => (rng.range(0..extent), y1),
+            1 => (x1, rng.range(0..extent)),
+            _ => {
+                let right = rng.chance(0.5);
+                let down = rng.chance(0.5);
+                let room_x = if right { extent - 1 - x1 } else { x1 };
+                let room_y = if down { extent - 1 - y1 } else { y1 };
+                let length = rng.range(0..room_x.min(room_y) + 1);
+                (
+                    if right { x1 + length } else { x1 - length },
+                    if down { y1 + length } else { y1 - length },
+                )
+            }
+        };
+        input.push_str(&format!("{},{} -> {},{}\n", x1, y1, x2, y2));
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real input.  Only the number of lines grows
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 500 * scale, 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{count_overlaps, parse_str, Backend};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(5);
+        for _ in 0..20 {
+            let segments = parse_str(&generate(&mut rng, 50, 40)).unwrap();
+
+            for diagonals in [false, true] {
+                // Every point of every counted line, with the overlaps next to each other
+                let mut points: Vec<_> = segments
+                    .iter()
+                    .filter(|segment| diagonals || segment.is_axis_aligned())
+                    .flat_map(|segment| segment.points())
+                    .collect();
+                points.sort_unstable();
+                let mut overlaps: Vec<_> = points
+                    .windows(2)
+                    .filter(|pair| pair[0] == pair[1])
+                    .map(|pair| pair[0])
+                    .collect();
+                overlaps.dedup();
+
+                assert_eq!(
+                    count_overlaps(&segments, diagonals, Backend::Sparse),
+                    overlaps.len()
+                );
+                assert_eq!(
+                    count_overlaps(&segments, diagonals, Backend::Dense),
+                    overlaps.len()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_grid::Grid;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// (x, y) with x growing to the right and y growing down
+pub type Point = (usize, usize);
+
+/// The largest coordinate of any point, so that stepping along a segment as signed numbers can
+/// never overflow
+pub const MAX_COORDINATE: usize = isize::MAX as usize;
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+    const DAY: u8 = 5;
+    type Input = Vec<Segment>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+/// A line of vents.  Every segment is horizontal, vertical or at exactly 45 degrees, and no
+/// coordinate is larger than [`MAX_COORDINATE`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl Segment {
+    pub fn is_axis_aligned(&self) -> bool {
+        self.from.0 == self.to.0 || self.from.1 == self.to.1
+    }
+
+    /// Every point on the segment, both ends included
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (from_x, from_y) = (self.from.0 as isize, self.from.1 as isize);
+        let (to_x, to_y) = (self.to.0 as isize, self.to.1 as isize);
+        let (step_x, step_y) = ((to_x - from_x).signum(), (to_y - from_y).signum());
+        let length = (to_x - from_x).abs().max((to_y - from_y).abs());
+
+        (0..=length).map(move |step| {
+            (
+                (from_x + step * step_x) as usize,
+                (from_y + step * step_y) as usize,
+            )
+        })
+    }
+}
+
+/// Where the vents are counted.  A dense grid is much faster, but needs a cell for every point
+/// inside the lines' bounding box, which is too much for lines with very large coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// A count for every point that has a vent
+    Sparse,
+    /// A count for every point from (0, 0) to the furthest end of any line
+    Dense,
+}
+
+impl Backend {
+    /// The most cells a dense grid is allowed before sparse is used instead
+    pub const DENSE_LIMIT: usize = 1 << 24;
+
+    /// Dense unless segments reach so far that the grid would be larger than [`Self::DENSE_LIMIT`]
+    pub fn for_segments(segments: &[Segment]) -> Self {
+        let (width, height) = bounds(segments);
+        match width.checked_mul(height) {
+            Some(cells) if cells <= Self::DENSE_LIMIT => Backend::Dense,
+            _ => Backend::Sparse,
+        }
+    }
+}
+
+/// Vents seen at each point, counting no higher than 2 since that is all that matters
+trait VentMap {
+    fn mark(&mut self, point: Point);
+
+    /// The number of points where at least two lines overlap
+    fn overlaps(&self) -> usize;
+}
+
+impl VentMap for HashMap<Point, u8> {
+    fn mark(&mut self, point: Point) {
+        let vents = self.entry(point).or_insert(0);
+        *vents = (*vents + 1).min(2);
+    }
+
+    fn overlaps(&self) -> usize {
+        self.values().filter(|vents| **vents >= 2).count()
+    }
+}
+
+impl VentMap for Grid<u8> {
+    fn mark(&mut self, (x, y): Point) {
+        let vents = &mut self[(y, x)];
+        *vents = (*vents + 1).min(2);
+    }
+
+    fn overlaps(&self) -> usize {
+        self.iter().filter(|vents| **vents >= 2).count()
+    }
+}
+
+/// The smallest (width, height) that holds every point of segments, or usize::MAX for a side
+/// that would be even larger
+fn bounds(segments: &[Segment]) -> (usize, usize) {
+    segments
+        .iter()
+        .flat_map(|segment| [segment.from, segment.to])
+        .fold((0, 0), |(width, height), (x, y)| {
+            (
+                width.max(x.saturating_add(1)),
+                height.max(y.saturating_add(1)),
+            )
+        })
+}
+
+/// The number of points where at least two segments overlap.  Diagonal segments are skipped
+/// unless diagonals is set
+pub fn count_overlaps(segments: &[Segment], diagonals: bool, backend: Backend) -> usize {
+    let mut vent_map: Box<dyn VentMap> = match backend {
+        Backend::Sparse => Box::new(HashMap::new()),
+        Backend::Dense => {
+            let (width, height) = bounds(segments);
+            Box::new(Grid::new(width, height, 0_u8))
+        }
+    };
+
+    for segment in segments {
+        if diagonals || segment.is_axis_aligned() {
+            for point in segment.points() {
+                vent_map.mark(point);
+            }
+        }
+    }
+
+    vent_map.overlaps()
+}
+
+/// Only horizontal and vertical lines count
+pub fn part_one(segments: &[Segment]) -> usize {
+    count_overlaps(segments, false, Backend::for_segments(segments))
+}
+
+pub fn part_two(segments: &[Segment]) -> usize {
+    count_overlaps(segments, true, Backend::for_segments(segments))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Segment>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Segment>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Segment>> {
+    parse_lines(input.lines())
+}
+
+/// One `x1,y1 -> x2,y2` segment per line.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Segment>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut segments = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (from, to) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::invalid(line_index, 0, line, "x1,y1 -> x2,y2"))?;
+        let segment = Segment {
+            from: parse_point(line_index, 0, from)?,
+            to: parse_point(line_index, from.len() + " -> ".len(), to)?,
+        };
+
+        let width = segment.from.0.abs_diff(segment.to.0);
+        let height = segment.from.1.abs_diff(segment.to.1);
+        if !segment.is_axis_aligned() && width != height {
+            return Err(ParseError::invalid(
+                line_index,
+                0,
+                line,
+                "a horizontal, vertical or 45 degree line",
+            ));
+        }
+        segments.push(segment);
+    }
+
+    Ok(segments)
+}
+
+fn parse_point(line_index: usize, column_index: usize, point: &str) -> ParseResult<Point> {
+    let invalid = || ParseError::invalid(line_index, column_index, point, "a point x,y");
+    let (x, y) = point.split_once(',').ok_or_else(invalid)?;
+    let coordinate = |coordinate: &str| {
+        coordinate
+            .trim()
+            .parse()
+            .ok()
+            .filter(|coordinate| *coordinate <= MAX_COORDINATE)
+            .ok_or_else(invalid)
+    };
+
+    Ok((coordinate(x)?, coordinate(y)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_overlaps, parse_input, parse_str, part_one, part_two, Backend, Segment,
+        MAX_COORDINATE,
+    };
+
+    #[test]
+    fn test_parse_input() {
+        let segments = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(segments.len(), 10);
+        assert_eq!(
+            segments[0],
+            Segment {
+                from: (0, 9),
+                to: (5, 9)
+            }
+        );
+        assert_eq!(
+            segments[9],
+            Segment {
+                from: (5, 5),
+                to: (8, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("0,9 -> 5,9\n1,1 -> 3,x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: expected a point x,y, found \"3,x\""
+        );
+        assert!(parse_str("0,9 => 5,9").is_err());
+        assert!(parse_str("0,0 -> 1,2").is_err());
+        let too_far = format!("0,0 -> 0,{}", usize::MAX);
+        assert!(parse_str(&too_far).is_err());
+    }
+
+    #[test]
+    fn test_points() {
+        let segment = Segment {
+            from: (3, 1),
+            to: (1, 3),
+        };
+        assert_eq!(
+            segment.points().collect::<Vec<_>>(),
+            vec![(3, 1), (2, 2), (1, 3)]
+        );
+        assert!(!segment.is_axis_aligned());
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let segments = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_one(&segments), 5);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let segments = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_two(&segments), 12);
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        let segments = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(Backend::for_segments(&segments), Backend::Dense);
+        for diagonals in [false, true] {
+            assert_eq!(
+                count_overlaps(&segments, diagonals, Backend::Sparse),
+                count_overlaps(&segments, diagonals, Backend::Dense)
+            );
+        }
+
+        // Far too many cells for a dense grid
+        let far =
+            parse_str("0,0 -> 0,5\n0,3 -> 0,4000000000\n4000000000,0 -> 4000000000,5\n").unwrap();
+        assert_eq!(Backend::for_segments(&far), Backend::Sparse);
+
+        // The grid for the furthest point allowed does not fit in a usize at all
+        let furthest = format!("0,0 -> {0},{0}\n", MAX_COORDINATE);
+        let furthest = parse_str(&furthest).unwrap();
+        assert_eq!(Backend::for_segments(&furthest), Backend::Sparse);
+        let edge = Segment {
+            from: (usize::MAX, 0),
+            to: (usize::MAX, 0),
+        };
+        assert_eq!(Backend::for_segments(&[edge]), Backend::Sparse);
+    }
+}
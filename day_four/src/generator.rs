@@ -0,0 +1,109 @@
+//! Random games of bingo.
+use aoc_common::Rng;
+
+/// Every number from 0 to largest_number is called once in a random order, followed by boards
+/// boards of board_size by board_size distinct numbers.  Every board wins eventually.  Panics if
+/// a board would need more numbers than there are
+pub fn generate(rng: &mut Rng, boards: usize, board_size: usize, largest_number: usize) -> String {
+    let mut numbers: Vec<usize> = (0..=largest_number).collect();
+    assert!(
+        board_size * board_size <= numbers.len(),
+        "a {0}x{0} board needs more than {1} numbers",
+        board_size,
+        numbers.len()
+    );
+
+    rng.shuffle(&mut numbers);
+    let called: Vec<String> = numbers.iter().map(usize::to_string).collect();
+    let mut input = called.join(",");
+    input.push('\n');
+
+    for _ in 0..boards {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..board_size * board_size].chunks(board_size) {
+            let row: Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real game
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 100 * scale, 5, 99)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{first_winning_score, last_winning_score, parse_str};
+    use aoc_common::Rng;
+    use std::collections::HashMap;
+
+    /// (the call a board wins on, its score) worked out from when each number is called rather
+    /// than by marking boards
+    fn wins(called: &[usize], board: &[Vec<usize>]) -> (usize, usize) {
+        let turn: HashMap<usize, usize> = called
+            .iter()
+            .enumerate()
+            .map(|(turn, number)| (*number, turn))
+            .collect();
+        let rows = board
+            .iter()
+            .map(|row| row.iter().map(|number| turn[number]).max());
+        let columns =
+            (0..board.len()).map(|column| board.iter().map(|row| turn[&row[column]]).max());
+        let winning_turn = rows.chain(columns).flatten().min().unwrap();
+
+        let unmarked: usize = board
+            .iter()
+            .flatten()
+            .filter(|number| turn[number] > winning_turn)
+            .sum();
+        (winning_turn, unmarked * called[winning_turn])
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(4);
+        let input = generate(&mut rng, 30, 5, 60);
+        let (called, boards) = parse_str(&input).unwrap();
+        assert_eq!(boards.len(), 30);
+
+        let board_values: Vec<Vec<Vec<usize>>> = input
+            .split("\n\n")
+            .skip(1)
+            .map(|board| {
+                board
+                    .lines()
+                    .map(|row| row.split_whitespace().map(|n| n.parse().unwrap()).collect())
+                    .collect()
+            })
+            .collect();
+        let results: Vec<(usize, usize)> = board_values
+            .iter()
+            .map(|board| wins(&called, board))
+            .collect();
+
+        // Ties go to the board that comes first, or last for the last board to win
+        let first = results
+            .iter()
+            .enumerate()
+            .min_by_key(|(index, (turn, _))| (*turn, *index));
+        let last = results
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, (turn, _))| (*turn, *index));
+        assert_eq!(
+            first_winning_score(&called, boards.clone()).unwrap(),
+            (first.unwrap().1).1
+        );
+        assert_eq!(
+            last_winning_score(&called, boards).unwrap(),
+            (last.unwrap().1).1
+        );
+    }
+}
@@ -1,60 +1,127 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-// It's late so I'm going to assume that any boards passed in are valid.
-// By valid I mean that all rows have the same number of columns
-pub fn parse_input<P>(input_path: P) -> Result<(Vec<usize>, Vec<Board>)>
+pub struct DayFour;
+
+impl Solution for DayFour {
+    const DAY: u8 = 4;
+    type Input = (Vec<usize>, Vec<Board>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        first_winning_score(&input.0, input.1.clone())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        last_winning_score(&input.0, input.1.clone())
+    }
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<(Vec<usize>, Vec<Board>)>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
-
-    let mut lines = reader.lines().flatten();
-    let called_numbers = lines
-        .next()
-        .unwrap()
-        .trim()
-        .split(',')
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    lines.next(); // There is a blank space between board
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<(Vec<usize>, Vec<Board>)>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<(Vec<usize>, Vec<Board>)> {
+    parse_lines(input.lines())
+}
+
+/// Every row of a board must have the same number of columns
+fn parse_lines<I>(lines: I) -> ParseResult<(Vec<usize>, Vec<Board>)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut lines = lines.into_iter().enumerate();
+    let called_numbers = match lines.next() {
+        Some((line_index, line)) => parse_numbers(line_index, line.as_ref(), ',')?,
+        None => return Err(ParseError::unexpected_end(0, "the called numbers")),
+    };
 
     let mut created_boards = Vec::new();
-    let mut current_board = Vec::new();
-    while let Some(next) = lines.next() {
+    let mut current_board: Vec<Vec<usize>> = Vec::new();
+    for (line_index, next) in lines {
+        let next = next.as_ref();
+        // There is a blank space between boards
         if next.trim().is_empty() {
-            created_boards.push(Board::new(current_board));
-            current_board = Vec::new();
+            if !current_board.is_empty() {
+                created_boards.push(Board::new(current_board));
+                current_board = Vec::new();
+            }
         } else {
-            current_board.push(
-                next.trim()
-                    .split(' ')
-                    .filter(|x| !x.is_empty())
-                    .map(|x| x.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>(),
-            )
+            let row = parse_numbers(line_index, next, ' ')?;
+            if let Some(first_row) = current_board.first() {
+                if first_row.len() != row.len() {
+                    return Err(ParseError::invalid(
+                        line_index,
+                        0,
+                        next,
+                        format!("a board row of {} numbers", first_row.len()),
+                    ));
+                }
+            }
+            current_board.push(row)
         }
     }
-    if current_board.len() > 0 {
+    if !current_board.is_empty() {
         created_boards.push(Board::new(current_board));
     }
 
     Ok((called_numbers, created_boards))
 }
 
+/// Repeated separators are skipped so that the columns of a board can be lined up with spaces
+fn parse_numbers(line_index: usize, line: &str, separator: char) -> ParseResult<Vec<usize>> {
+    let mut numbers = Vec::new();
+    let mut column_index = 0;
+    for number in line.trim_end().split(separator) {
+        if !number.is_empty() {
+            numbers.push(number.parse::<usize>().map_err(|_| {
+                ParseError::invalid(line_index, column_index, number, "a non-negative integer")
+            })?);
+        }
+        column_index += number.len() + separator.len_utf8();
+    }
+
+    Ok(numbers)
+}
+
 pub fn part_one<P>(input_path: P) -> Result<usize>
 where
     P: AsRef<Path>,
 {
-    let (called_numbers, mut boards) = parse_input(input_path)?;
-    for number in called_numbers {
+    let (called_numbers, boards) = parse_input(input_path)?;
+
+    first_winning_score(&called_numbers, boards)
+}
+
+fn first_winning_score(called_numbers: &[usize], mut boards: Vec<Board>) -> Result<usize> {
+    for number in called_numbers.iter().copied() {
         for board in &mut boards {
             if board.call_number(number).is_some() {
-                return Ok(board.non_marked().into_iter().fold(0, |acc, x| acc + x) * number);
+                return Ok(board.non_marked().into_iter().sum::<usize>() * number);
             }
         }
     }
@@ -66,14 +133,18 @@ pub fn part_two<P>(input_path: P) -> Result<usize>
 where
     P: AsRef<Path>,
 {
-    let (called_numbers, mut boards) = parse_input(input_path)?;
+    let (called_numbers, boards) = parse_input(input_path)?;
+
+    last_winning_score(&called_numbers, boards)
+}
+
+fn last_winning_score(called_numbers: &[usize], mut boards: Vec<Board>) -> Result<usize> {
     let mut winning_scores = Vec::with_capacity(boards.len());
     let mut new_winning = Vec::new();
-    for number in called_numbers {
+    for number in called_numbers.iter().copied() {
         for (board_num, board) in &mut boards.iter_mut().enumerate() {
             if board.call_number(number).is_some() {
-                winning_scores
-                    .push(board.non_marked().into_iter().fold(0, |acc, x| acc + x) * number);
+                winning_scores.push(board.non_marked().into_iter().sum::<usize>() * number);
                 new_winning.push(board_num);
             }
         }
@@ -89,7 +160,7 @@ where
         Err(anyhow!("No board had bingo by the end"))
     }
 }
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Board {
     internal_hits: Vec<Vec<bool>>,
     values: Vec<Vec<usize>>,
@@ -99,7 +170,7 @@ pub struct Board {
 impl Board {
     pub fn new(chosen_numbers: Vec<Vec<usize>>) -> Self {
         let mut values_index =
-            HashMap::with_capacity(chosen_numbers.len() * chosen_numbers.get(0).unwrap().len());
+            HashMap::with_capacity(chosen_numbers.len() * chosen_numbers.first().unwrap().len());
         for (row_index, row) in chosen_numbers.iter().enumerate() {
             for (col_index, number) in row.iter().enumerate() {
                 values_index.insert(*number, (row_index, col_index));
@@ -143,21 +214,14 @@ impl Board {
     }
 
     fn column_check(&self) -> Option<Vec<usize>> {
-        let num_columns = self.internal_hits.get(0).unwrap().len();
+        let num_columns = self.internal_hits.first().unwrap().len();
 
         for column_num in 0..num_columns {
-            let column_result = self.internal_hits.iter().fold(true, |acc, row| {
-                if acc {
-                    // If this number has not been called
-                    if !row.get(column_num).unwrap() {
-                        false
-                    } else {
-                        true
-                    }
-                } else {
-                    false
-                }
-            });
+            // If any number in this column has not been called it is not a bingo
+            let column_result = self
+                .internal_hits
+                .iter()
+                .all(|row| *row.get(column_num).unwrap());
 
             if column_result {
                 return Some(self.values.iter().fold(Vec::new(), |mut acc, row| {
@@ -169,53 +233,6 @@ impl Board {
         None
     }
 
-    fn diag_check(&self) -> Option<Vec<usize>> {
-        if self.internal_hits.len() != self.internal_hits.get(0).unwrap().len() {
-            return None;
-        }
-
-        let mut found_bingo = true;
-        let mut bingo_result: Vec<usize> = Vec::with_capacity(self.internal_hits.len());
-
-        for (index, row) in self.internal_hits.iter().enumerate() {
-            if !row.get(index).unwrap() {
-                found_bingo = false;
-                break;
-            } else {
-                bingo_result.push(*self.values.get(index).unwrap().get(index).unwrap());
-            }
-        }
-
-        if found_bingo {
-            return Some(bingo_result);
-        }
-
-        bingo_result.clear();
-        found_bingo = true;
-
-        for (index, row) in self.internal_hits.iter().rev().enumerate() {
-            if !row.get(index).unwrap() {
-                found_bingo = false;
-                break;
-            } else {
-                bingo_result.push(
-                    *self
-                        .values
-                        .get(self.internal_hits.len() - index - 1)
-                        .unwrap()
-                        .get(index)
-                        .unwrap(),
-                );
-            }
-        }
-
-        if found_bingo {
-            return Some(bingo_result);
-        } else {
-            None
-        }
-    }
-
     pub fn call_number(&mut self, called_number: usize) -> Option<Vec<usize>> {
         let found_number = self.values_index.get(&called_number);
         match found_number {
@@ -242,7 +259,7 @@ impl Board {
 
 #[cfg(test)]
 mod tests {
-    use crate::{part_one, part_two, Board};
+    use crate::{parse_lines, part_one, part_two, Board};
     fn create_5_by_5() -> Board {
         let board_values = vec![
             vec![1, 2, 3, 4, 5],
@@ -254,23 +271,6 @@ mod tests {
 
         Board::new(board_values)
     }
-    //    #[test] Disabled as diag isn't in part one
-    fn test_diag() {
-        let mut board = create_5_by_5();
-        assert_eq!(board.call_number(1), None);
-        assert_eq!(board.call_number(7), None);
-        assert_eq!(board.call_number(13), None);
-        assert_eq!(board.call_number(19), None);
-        assert_eq!(board.call_number(25).unwrap(), vec![1, 7, 13, 19, 25]);
-
-        board = create_5_by_5();
-        assert_eq!(board.call_number(5), None);
-        assert_eq!(board.call_number(9), None);
-        assert_eq!(board.call_number(13), None);
-        assert_eq!(board.call_number(17), None);
-        let result = board.call_number(21);
-        assert_eq!(result.unwrap(), vec![21, 17, 13, 9, 5]);
-    }
 
     #[test]
     fn test_rows() {
@@ -320,18 +320,23 @@ mod tests {
     fn part_one_example() {
         assert_eq!(part_one("src/example_input.txt").unwrap(), 4512);
     }
-    #[test]
-    fn part_one_my_input() {
-        assert_eq!(part_one("src/input.txt").unwrap(), 71708);
-    }
 
     #[test]
-    fn part_two_my_input() {
-        assert_eq!(part_two("src/input.txt").unwrap(), 34726);
+    fn part_two_example_input() {
+        assert_eq!(part_two("src/example_input.txt").unwrap(), 1924);
     }
 
     #[test]
-    fn part_two_example_input() {
-        assert_eq!(part_two("src/example_input.txt").unwrap(), 1924);
+    fn test_parse_lines_invalid() {
+        let error = parse_lines(["7,4,x9"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a non-negative integer, found \"x9\""
+        );
+
+        let error = parse_lines(["7,4", "", " 1  2", " 3  4  5"]).unwrap_err();
+        assert_eq!(error.line(), Some(4));
+
+        assert!(parse_lines(Vec::<String>::new()).is_err());
     }
 }
@@ -0,0 +1,59 @@
+//! Random polymer templates and insertion rules.
+use aoc_common::Rng;
+
+/// A template of template_length elements drawn from the first elements letters of the alphabet,
+/// followed by a rule for every pair of those elements like the real input has.  Panics if
+/// elements is not between 1 and 26
+pub fn generate(rng: &mut Rng, template_length: usize, elements: usize) -> String {
+    assert!(
+        (1..=26).contains(&elements),
+        "there are only 26 elements, not {}",
+        elements
+    );
+    let letters: Vec<char> = ('A'..='Z').take(elements).collect();
+
+    let mut input: String = (0..template_length.max(1))
+        .map(|_| *rng.choose(&letters))
+        .collect();
+    input.push_str("\n\n");
+    for first in &letters {
+        for second in &letters {
+            input.push_str(&format!(
+                "{}{} -> {}\n",
+                first,
+                second,
+                rng.choose(&letters)
+            ));
+        }
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real input.  Only the template grows, since there are
+/// already rules for every pair
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 20 * scale, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{count_polymer_pairs, grow_polymer_one_step_at_a_time, parse_str};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_growing_matches_counting_pairs() {
+        let mut rng = Rng::new(14);
+        for (template_length, elements) in [(1, 1), (2, 3), (20, 10), (50, 26)] {
+            let (template, rules) =
+                parse_str(&generate(&mut rng, template_length, elements)).unwrap();
+            assert_eq!(rules.len(), elements * elements);
+
+            assert_eq!(
+                grow_polymer_one_step_at_a_time(template.clone(), &rules).unwrap(),
+                count_polymer_pairs(&template, &rules, 10).unwrap()
+            );
+        }
+    }
+}
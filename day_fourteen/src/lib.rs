@@ -1,4 +1,7 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::{
     collections::HashMap,
     fs::File,
@@ -6,51 +9,118 @@ use std::{
     path::Path,
 };
 
-fn parse_input(input_lines: Vec<String>) -> Result<(Vec<char>, HashMap<(char, char), char>)> {
-    let mut input_lines_iter = input_lines.into_iter();
-
-    let line_one = input_lines_iter.next().expect("This is me being lazy");
-    let return_vec = line_one.chars().collect::<Vec<char>>();
-
-    let _empty_next = input_lines_iter.next().expect("empty line");
-
-    let mut return_hash_map: HashMap<(char, char), char> = HashMap::new();
-    for other_line in input_lines_iter {
-        let mut other_line_split = other_line.split(" -> ");
-        if let (Some(rules), Some(result)) = (other_line_split.next(), other_line_split.next()) {
-            let mut rules = rules.chars();
-            if let (Some(a), Some(b)) = (rules.next(), rules.next()) {
-                return_hash_map.insert(
-                    (a, b),
-                    result
-                        .chars()
-                        .next()
-                        .ok_or_else(|| anyhow!("malformed rule"))?,
-                );
-            } else {
-                return Err(anyhow!("Some rule was malformed"));
+pub struct DayFourteen;
+
+impl Solution for DayFourteen {
+    const DAY: u8 = 14;
+    type Input = (Vec<char>, Rules);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        grow_polymer_one_step_at_a_time(input.0.clone(), &input.1)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        count_polymer_pairs(&input.0, &input.1, 40)
+    }
+}
+
+/// The element inserted between each pair of elements
+pub type Rules = HashMap<(char, char), char>;
+
+/// The polymer template, a blank line and then one `AB -> C` pair insertion rule per line
+fn parse_input<I>(input_lines: I) -> ParseResult<(Vec<char>, Rules)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut input_lines_iter = input_lines.into_iter().enumerate();
+
+    let return_vec = match input_lines_iter.next() {
+        Some((_, line_one)) if !line_one.as_ref().trim().is_empty() => {
+            line_one.as_ref().trim().chars().collect::<Vec<char>>()
+        }
+        _ => return Err(ParseError::unexpected_end(0, "the polymer template")),
+    };
+
+    match input_lines_iter.next() {
+        Some((_, empty_next)) if empty_next.as_ref().trim().is_empty() => (),
+        Some((line_index, not_empty)) => {
+            return Err(ParseError::invalid(
+                line_index,
+                0,
+                not_empty.as_ref(),
+                "a blank line",
+            ))
+        }
+        None => return Err(ParseError::unexpected_end(1, "a blank line")),
+    }
+
+    let mut return_hash_map: Rules = HashMap::new();
+    for (line_index, other_line) in input_lines_iter {
+        let other_line = other_line.as_ref().trim();
+        let rule = other_line.split_once(" -> ").and_then(|(rules, result)| {
+            let rules: Vec<char> = rules.chars().collect();
+            let result: Vec<char> = result.chars().collect();
+            match (rules.as_slice(), result.as_slice()) {
+                ([a, b], [c]) => Some(((*a, *b), *c)),
+                _ => None,
+            }
+        });
+
+        match rule {
+            Some((pair, insert)) => {
+                return_hash_map.insert(pair, insert);
+            }
+            None => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    other_line,
+                    "a rule of the form AB -> C",
+                ))
             }
-        } else {
-            return Err(anyhow!("Some rule line was malformed"));
         }
     }
 
     Ok((return_vec, return_hash_map))
 }
-pub fn parse_input_file<P>(input_path: P) -> Result<Vec<String>>
+pub fn parse_input_file<P>(input_path: P) -> ParseResult<Vec<String>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
 
-    Ok(reader.lines().flatten().collect::<Vec<String>>())
+    aoc_common::read_lines(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<(Vec<char>, Rules)>
+where
+    R: BufRead,
+{
+    parse_input(aoc_common::read_lines(reader)?)
 }
 
-fn part_one(input_lines: Vec<String>) -> Result<usize> {
-    let (mut working_string_vec, rule_map) = parse_input(input_lines)?;
+pub fn parse_str(input: &str) -> ParseResult<(Vec<char>, Rules)> {
+    parse_input(input.lines())
+}
 
-    for _step in 0..10 {
+pub fn part_one(input_lines: Vec<String>) -> Result<usize> {
+    let (working_string_vec, rule_map) = parse_input(input_lines)?;
+
+    grow_polymer_one_step_at_a_time(working_string_vec, &rule_map)
+}
+
+fn grow_polymer_one_step_at_a_time(
+    mut working_string_vec: Vec<char>,
+    rule_map: &Rules,
+) -> Result<usize> {
+    for step in 1..=10 {
         let mut next_vec =
             working_string_vec
                 .as_slice()
@@ -70,8 +140,12 @@ fn part_one(input_lines: Vec<String>) -> Result<usize> {
                 .expect("working_string_vec not enpty"),
         );
         working_string_vec = next_vec;
+        log::trace!(
+            "step {}: polymer is {} long",
+            step,
+            working_string_vec.len()
+        );
     }
-    //dbg!(&working_string_vec);
 
     let count_map: HashMap<char, usize> =
         working_string_vec
@@ -92,11 +166,20 @@ fn part_one(input_lines: Vec<String>) -> Result<usize> {
     Ok(most_common - least_common)
 }
 
-fn part_two(input_lines: Vec<String>, step_count: usize) -> Result<usize> {
+pub fn part_two(input_lines: Vec<String>, step_count: usize) -> Result<usize> {
+    let (initial_string_as_chars, rules) = parse_input(input_lines)?;
+
+    count_polymer_pairs(&initial_string_as_chars, &rules, step_count)
+}
+
+fn count_polymer_pairs(
+    initial_string_as_chars: &[char],
+    rules: &Rules,
+    step_count: usize,
+) -> Result<usize> {
     // I am going to create two hashmaps.
     // The first is the number of each pair. This is so that I don't need to walk all the pairs and apply a rule for each one.  I can do each rule all at once.
     // The second is the current number of each char.   This is because we can't determine the number of each char by the pair counts alone
-    let (initial_string_as_chars, rules) = parse_input(input_lines)?;
     let mut pair_counts: HashMap<(char, char), usize> = HashMap::new();
 
     // Get the current counts
@@ -108,14 +191,14 @@ fn part_two(input_lines: Vec<String>, step_count: usize) -> Result<usize> {
             .or_insert(1);
     }
 
-    for window in initial_string_as_chars.as_slice().windows(2) {
+    for window in initial_string_as_chars.windows(2) {
         pair_counts
             .entry((window[0], window[1]))
             .and_modify(|e| *e += 1)
             .or_insert(1);
     }
 
-    for _step in 0..step_count {
+    for step in 1..=step_count {
         let mut next_counts: HashMap<(char, char), usize> =
             HashMap::with_capacity(pair_counts.len());
         for (pattern, result) in rules.iter() {
@@ -137,6 +220,12 @@ fn part_two(input_lines: Vec<String>, step_count: usize) -> Result<usize> {
             }
         }
         pair_counts = next_counts;
+        log::trace!(
+            "step {}: {} distinct pairs, polymer is {} long",
+            step,
+            pair_counts.len(),
+            char_counts.values().sum::<usize>()
+        );
     }
 
     Ok(*char_counts
@@ -153,7 +242,7 @@ fn part_two(input_lines: Vec<String>, step_count: usize) -> Result<usize> {
 mod tests {
     use std::collections::HashMap;
 
-    use crate::{parse_input, parse_input_file, part_one, part_two};
+    use crate::{parse_input, part_one, part_two};
 
     #[test]
     fn it_works() {
@@ -209,6 +298,18 @@ mod tests {
         assert_eq!(actual_map, expected_map);
     }
 
+    #[test]
+    fn test_parse_input_invalid() {
+        let error = parse_input(["NNCB", "", "CH -> B", "HHH -> N"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected a rule of the form AB -> C, found \"HHH -> N\""
+        );
+
+        assert!(parse_input(["NNCB", "CH -> B"]).is_err());
+        assert!(parse_input(Vec::<String>::new()).is_err());
+    }
+
     #[test]
     fn test_part_one_example() {
         let input = vec![
@@ -237,32 +338,6 @@ mod tests {
         assert_eq!(result.expect("already aseretd"), 1588);
     }
 
-    #[test]
-    fn part_one_my_input() {
-        let lines = parse_input_file("src/my_input.txt");
-        assert!(lines.is_ok());
-        let lines = lines.expect("already asserted");
-        let result = part_one(lines);
-        dbg!(&result);
-        assert!(result.is_ok());
-        let result = result.expect("already asserted");
-
-        assert_eq!(result, 2170);
-    }
-
-    #[test]
-    fn part_two_my_input() {
-        let lines = parse_input_file("src/my_input.txt");
-        assert!(lines.is_ok(), "Failed to read my input");
-        let lines = lines.expect("already asserted");
-        let result = part_two(lines, 40);
-        dbg!(&result);
-        assert!(result.is_ok());
-        let result = result.expect("already asserted");
-
-        assert_eq!(result, 2422444761283);
-    }
-
     #[test]
     fn test_part_two_example() {
         let input = vec![
@@ -0,0 +1,99 @@
+//! Random heightmaps.
+//!
+//! Like the real input every location that is not a 9 belongs to exactly one basin, and every
+//! basin flows down to a single low point.
+use aoc_common::Rng;
+use aoc_grid::{Grid, Position};
+
+/// A width by height heightmap with up to basins basins.  Fewer basins are carved when the map
+/// runs out of room
+pub fn generate(rng: &mut Rng, width: usize, height: usize, basins: usize) -> String {
+    let (heightmap, _) = carve(rng, width, height, basins);
+
+    heightmap
+        .rows()
+        .map(|row| {
+            let mut line: String = row.iter().map(|height| height.to_string()).collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real heightmap.  The map gets taller as it scales
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 100, 100 * scale, 250 * scale)
+}
+
+/// The heightmap and the size of every basin in it.
+///
+/// Each basin grows outwards from its low point one random neighbor at a time and every location
+/// is one higher than the location it grew from, so only the low point has nowhere lower to flow.
+/// Basins never touch, which leaves a wall of 9s between them.
+fn carve(rng: &mut Rng, width: usize, height: usize, basins: usize) -> (Grid<u8>, Vec<usize>) {
+    let mut heightmap = Grid::new(width, height, 9);
+    // Which basin each location belongs to
+    let mut owner: Grid<Option<usize>> = Grid::new(width, height, None);
+    let mut sizes = Vec::with_capacity(basins);
+
+    let free = |owner: &Grid<Option<usize>>, position: Position, basin: usize| {
+        owner[position].is_none()
+            && owner
+                .neighbors4(position)
+                .all(|neighbor| owner[neighbor].is_none() || owner[neighbor] == Some(basin))
+    };
+
+    for basin in 0..basins {
+        let low_point = match (0..20)
+            .map(|_| (rng.range(0..height.max(1)), rng.range(0..width.max(1))))
+            .find(|position| owner.contains(*position) && free(&owner, *position, basin))
+        {
+            Some(low_point) => low_point,
+            None => continue,
+        };
+
+        let target_size = rng.range(1..width.max(height).max(2));
+        let mut frontier = vec![(low_point, 0)];
+        let mut size = 0;
+        while size < target_size && !frontier.is_empty() {
+            let (position, depth) = frontier.swap_remove(rng.range(0..frontier.len()));
+            if !free(&owner, position, basin) {
+                continue;
+            }
+
+            owner[position] = Some(basin);
+            heightmap[position] = depth.min(8);
+            size += 1;
+            frontier.extend(
+                heightmap
+                    .neighbors4(position)
+                    .map(|neighbor| (neighbor, depth + 1)),
+            );
+        }
+        sizes.push(size);
+    }
+
+    (heightmap, sizes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::carve;
+    use crate::{part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(9);
+        for _ in 0..100 {
+            let (heightmap, mut sizes) = carve(&mut rng, 40, 30, 25);
+            let heightmap = heightmap.map(|height| *height as usize);
+
+            // Every low point has a height of 0 so each is worth 1
+            assert_eq!(part_one(&heightmap), sizes.len());
+
+            sizes.sort_unstable();
+            assert_eq!(part_two(&heightmap), sizes.iter().rev().take(3).product());
+        }
+    }
+}
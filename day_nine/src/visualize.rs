@@ -0,0 +1,75 @@
+//! Images of the basins filling up from their low points.
+use crate::{find_low_points, grow_basins, parse_str, Basin};
+use anyhow::Result;
+use aoc_grid::{Grid, Position};
+use aoc_image::{ColorMap, Frames, Image, Rgb};
+use std::collections::HashMap;
+
+/// Write a frame of the bare heightmap and then one for every step the basins grow
+pub fn basins(heightmap: &Grid<usize>, frames: &mut Frames) -> Result<()> {
+    let low_points = find_low_points(heightmap);
+    let mut basins: Vec<Basin> = low_points
+        .iter()
+        .map(|low_point| HashMap::from([(*low_point, ())]))
+        .collect();
+    let mut new_nodes: Vec<(usize, Position)> = low_points.into_iter().enumerate().collect();
+
+    frames.push(&render(heightmap, &basins))?;
+    loop {
+        new_nodes = grow_basins(heightmap, &mut basins, new_nodes);
+        if new_nodes.is_empty() {
+            return Ok(());
+        }
+        frames.push(&render(heightmap, &basins))?;
+    }
+}
+
+/// Parse input and write the frames of [`basins`]
+pub fn visualize(input: &str, frames: &mut Frames) -> Result<()> {
+    basins(&parse_str(input)?, frames)
+}
+
+/// Cells that are in a basin are the color of their basin and every other cell is a shade of gray
+/// that is lighter the higher it is
+fn render(heightmap: &Grid<usize>, basins: &[Basin]) -> Image {
+    let mut pixels = heightmap.map(|height| Rgb::gray((height.min(&9) * 25) as u8));
+    for (index, basin) in basins.iter().enumerate() {
+        // Stepping by the golden ratio keeps neighbouring basins from looking alike
+        let color = ColorMap::Viridis.color((index as f64 * 0.618_034).fract());
+        for position in basin.keys() {
+            pixels[*position] = color;
+        }
+    }
+
+    Image::Pixmap(pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_str;
+    use crate::visualize::basins;
+    use aoc_image::Frames;
+
+    #[test]
+    fn test_basins() {
+        let heightmap =
+            parse_str("2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n").unwrap();
+        let directory =
+            std::env::temp_dir().join(format!("day_nine_basins_{}", std::process::id()));
+        let mut frames = Frames::create(&directory, "basins").unwrap();
+        basins(&heightmap, &mut frames).unwrap();
+
+        // The slowest basin takes 4 steps to fill from its low point
+        assert_eq!(frames.len(), 5);
+        let last = std::fs::read(directory.join("basins_00004.ppm")).unwrap();
+        let pixels = &last[b"P6\n10 5\n255\n".len()..];
+        // Once the basins are full the only gray cells left are the walls
+        let gray = pixels
+            .chunks(3)
+            .filter(|pixel| pixel[0] == pixel[1] && pixel[1] == pixel[2])
+            .count();
+        assert_eq!(gray, 15);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
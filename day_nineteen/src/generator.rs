@@ -0,0 +1,158 @@
+//! Random scanner reports.
+use crate::{Point, Rotation, Scanner, ScannerPose, MIN_OVERLAP};
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+/// How far a scanner sees along each axis
+const RANGE: isize = 1000;
+
+/// The reports of scanners scanners spread out so that each overlaps one placed before it.
+/// Every scanner after the first is turned a random way
+pub fn generate(rng: &mut Rng, scanners: usize) -> String {
+    let (reports, _) = random_scanners(rng, scanners);
+
+    reports
+        .iter()
+        .enumerate()
+        .map(|(index, scanner)| {
+            let beacons: String = scanner
+                .beacons
+                .iter()
+                .map(|[x, y, z]| format!("{},{},{}\n", x, y, z))
+                .collect();
+            format!("--- scanner {} ---\n{}", index, beacons)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A scale of 1 is about the size of the real input
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 30 * scale)
+}
+
+/// Each scanner's report along with where it really is.  A new scanner is placed near an
+/// earlier one and gets enough beacons in the space both see to be aligned with it, plus a
+/// few that only it sees.  Reports hold every beacon in range, whoever it was made for
+fn random_scanners(rng: &mut Rng, scanners: usize) -> (Vec<Scanner>, Vec<ScannerPose>) {
+    let rotations = Rotation::all();
+    let mut poses = vec![ScannerPose::ORIGIN];
+    let mut beacons: HashSet<Point> = HashSet::new();
+    add_beacons(rng, &mut beacons, [[-RANGE; 3], [RANGE; 3]], MIN_OVERLAP);
+
+    // Scanners that have not been a neighbour twice yet, so no one scanner ends up seeing
+    // most of the beacons
+    let mut open = vec![0];
+    let mut neighbours = vec![0; scanners.max(1)];
+    while poses.len() < scanners {
+        let neighbour_index = *rng.choose(&open);
+        let neighbour = poses[neighbour_index];
+        // Well away along one axis and not too far off along the others, so the two see a
+        // slab of space together but little of anything else
+        let far_axis = rng.range(0..3);
+        let position = [0, 1, 2].map(|axis| {
+            let offset = if axis == far_axis {
+                1000 + rng.range(0..300)
+            } else {
+                rng.range(0..600)
+            } as isize;
+            neighbour.position[axis] + if rng.chance(0.5) { offset } else { -offset }
+        });
+        let crowded = poses
+            .iter()
+            .any(|pose| (0..3).all(|axis| pose.position[axis].abs_diff(position[axis]) < 900));
+        if crowded {
+            continue;
+        }
+
+        // The box both the neighbour and the new scanner see
+        let low = [0, 1, 2].map(|axis| neighbour.position[axis].max(position[axis]) - RANGE);
+        let high = [0, 1, 2].map(|axis| neighbour.position[axis].min(position[axis]) + RANGE);
+        let shared = MIN_OVERLAP + rng.range(0..4);
+        add_beacons(rng, &mut beacons, [low, high], shared);
+        let own = rng.range(4..12);
+        let corners = [RANGE, -RANGE].map(|edge| position.map(|axis| axis - edge));
+        add_beacons(rng, &mut beacons, corners, own);
+
+        neighbours[neighbour_index] += 1;
+        if neighbours[neighbour_index] == 2 {
+            open.retain(|index| *index != neighbour_index);
+        }
+        open.push(poses.len());
+        poses.push(ScannerPose {
+            rotation: *rng.choose(&rotations),
+            position,
+        });
+    }
+
+    let mut beacons: Vec<Point> = beacons.into_iter().collect();
+    beacons.sort_unstable();
+    let reports = poses
+        .iter()
+        .map(|pose| {
+            let unturn = pose.rotation.inverse();
+            let mut seen: Vec<Point> = beacons
+                .iter()
+                .filter(|beacon| {
+                    (0..3).all(|axis| beacon[axis].abs_diff(pose.position[axis]) <= RANGE as usize)
+                })
+                .map(|beacon| {
+                    unturn.apply([0, 1, 2].map(|axis| beacon[axis] - pose.position[axis]))
+                })
+                .collect();
+            rng.shuffle(&mut seen);
+            Scanner { beacons: seen }
+        })
+        .collect();
+
+    (reports, poses)
+}
+
+/// count new beacons anywhere in the box from corners[0] to corners[1]
+fn add_beacons(rng: &mut Rng, beacons: &mut HashSet<Point>, corners: [Point; 2], count: usize) {
+    let mut added = 0;
+    while added < count {
+        let beacon = [0, 1, 2].map(|axis| {
+            corners[0][axis]
+                + rng.range(0..(corners[1][axis] - corners[0][axis]) as usize + 1) as isize
+        });
+        if beacons.insert(beacon) {
+            added += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{generate, random_scanners};
+    use crate::{align, parse_str};
+    use aoc_common::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(19);
+        let scanners = parse_str(&generate(&mut rng, 6)).unwrap();
+        assert_eq!(scanners.len(), 6);
+        assert!(align(&scanners).is_ok());
+    }
+
+    #[test]
+    fn test_align_finds_true_poses() {
+        let mut rng = Rng::new(91);
+        for _ in 0..3 {
+            let (scanners, poses) = random_scanners(&mut rng, 8);
+            let alignment = align(&scanners).unwrap();
+            assert_eq!(alignment.poses, poses);
+
+            let beacons: HashSet<_> = scanners
+                .iter()
+                .zip(&poses)
+                .flat_map(|(scanner, pose)| {
+                    scanner.beacons.iter().map(|beacon| pose.apply(*beacon))
+                })
+                .collect();
+            assert_eq!(alignment.beacons.len(), beacons.len());
+        }
+    }
+}
@@ -0,0 +1,431 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// (x, y, z) relative to whichever scanner it was seen from
+pub type Point = [isize; 3];
+
+pub struct DayNineteen;
+
+impl Solution for DayNineteen {
+    const DAY: u8 = 19;
+    type Input = Vec<Scanner>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(align(input)?.beacons.len())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(align(input)?.max_scanner_distance())
+    }
+}
+
+/// The beacons one scanner reported, in its own position and orientation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    pub beacons: Vec<Point>,
+}
+
+impl Scanner {
+    /// The squared distance between every two of its beacons, sorted.  These do not change
+    /// however the scanner is placed, so two scanners that share beacons share these too
+    pub fn fingerprint(&self) -> Vec<isize> {
+        let mut distances: Vec<isize> = self
+            .beacons
+            .iter()
+            .enumerate()
+            .flat_map(|(index, first)| {
+                self.beacons[index + 1..]
+                    .iter()
+                    .map(move |second| (0..3).map(|axis| (first[axis] - second[axis]).pow(2)).sum())
+            })
+            .collect();
+        distances.sort_unstable();
+        distances
+    }
+}
+
+/// One of the 24 ways to turn a scanner so that its axes line up with another's.  Axis i of
+/// the turned point is signs[i] times axis axes[i] of the original
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [isize; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
+
+    /// Every rotation, starting with [`Self::IDENTITY`].  Of the 48 ways to permute and negate
+    /// the axes, half are mirror images, which are left out
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [
+            ([0, 1, 2], 1),
+            ([0, 2, 1], -1),
+            ([1, 0, 2], -1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([2, 1, 0], -1),
+        ];
+
+        let mut rotations = Vec::with_capacity(24);
+        for (axes, parity) in permutations {
+            for negated in 0..8 {
+                let signs = [0, 1, 2].map(|axis| if negated & 1 << axis == 0 { 1 } else { -1 });
+                if parity * signs.iter().product::<isize>() == 1 {
+                    rotations.push(Rotation { axes, signs });
+                }
+            }
+        }
+
+        rotations
+    }
+
+    pub fn apply(&self, point: Point) -> Point {
+        [0, 1, 2].map(|axis| self.signs[axis] * point[self.axes[axis]])
+    }
+
+    /// The rotation that turns points back
+    pub fn inverse(&self) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+        for axis in 0..3 {
+            inverse.axes[self.axes[axis]] = axis;
+            inverse.signs[self.axes[axis]] = self.signs[axis];
+        }
+        inverse
+    }
+}
+
+/// Where a scanner is and which way it faces, both relative to scanner 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScannerPose {
+    pub rotation: Rotation,
+    pub position: Point,
+}
+
+impl ScannerPose {
+    pub const ORIGIN: ScannerPose = ScannerPose {
+        rotation: Rotation::IDENTITY,
+        position: [0, 0, 0],
+    };
+
+    /// point as seen by this scanner, relative to scanner 0 instead
+    pub fn apply(&self, point: Point) -> Point {
+        let rotated = self.rotation.apply(point);
+        [0, 1, 2].map(|axis| rotated[axis] + self.position[axis])
+    }
+}
+
+/// Every scanner placed relative to scanner 0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    /// In the same order as the scanners
+    pub poses: Vec<ScannerPose>,
+    /// Every beacon any scanner saw, once each, relative to scanner 0 and sorted
+    pub beacons: Vec<Point>,
+}
+
+impl Alignment {
+    /// The largest Manhattan distance between any two scanners
+    pub fn max_scanner_distance(&self) -> usize {
+        self.poses
+            .iter()
+            .flat_map(|first| self.poses.iter().map(move |second| (first, second)))
+            .map(|(first, second)| manhattan_distance(first.position, second.position))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub fn manhattan_distance(first: Point, second: Point) -> usize {
+    (0..3).map(|axis| first[axis].abs_diff(second[axis])).sum()
+}
+
+/// Scanners are placed once they share this many beacons with one that already is
+pub const MIN_OVERLAP: usize = 12;
+
+/// Place every scanner by growing out from scanner 0, which is where everything is measured
+/// from.  Fails if some scanners never overlap any placed scanner by [`MIN_OVERLAP`] beacons
+pub fn align(scanners: &[Scanner]) -> Result<Alignment> {
+    if scanners.is_empty() {
+        return Err(anyhow!("There are no scanners to align"));
+    }
+    let rotations = Rotation::all();
+    let fingerprints: Vec<_> = scanners.iter().map(Scanner::fingerprint).collect();
+    // Every two of the overlapping beacons are the same distance apart for both scanners
+    let min_shared_distances = MIN_OVERLAP * (MIN_OVERLAP - 1) / 2;
+
+    let mut poses = vec![None; scanners.len()];
+    poses[0] = Some(ScannerPose::ORIGIN);
+    // Placed scanners whose neighbours have not been looked for yet, with their beacons
+    // already moved relative to scanner 0
+    let mut to_visit = vec![(0, scanners[0].beacons.clone())];
+    while let Some((placed_index, placed)) = to_visit.pop() {
+        for (index, scanner) in scanners.iter().enumerate() {
+            if poses[index].is_some()
+                || shared_count(&fingerprints[placed_index], &fingerprints[index])
+                    < min_shared_distances
+            {
+                continue;
+            }
+            if let Some(pose) = find_pose(&placed, scanner, &rotations) {
+                log::debug!("Scanner {} is at {:?}", index, pose.position);
+                poses[index] = Some(pose);
+                let beacons = scanner.beacons.iter().map(|beacon| pose.apply(*beacon));
+                to_visit.push((index, beacons.collect()));
+            }
+        }
+    }
+
+    let poses = poses
+        .into_iter()
+        .enumerate()
+        .map(|(index, pose)| {
+            pose.ok_or_else(|| anyhow!("Scanner {} overlaps none of the others", index))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut beacons: Vec<Point> = scanners
+        .iter()
+        .zip(&poses)
+        .flat_map(|(scanner, pose)| scanner.beacons.iter().map(|beacon| pose.apply(*beacon)))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    beacons.sort_unstable();
+
+    Ok(Alignment { poses, beacons })
+}
+
+/// The number of values in both sorted lists, counting repeats as often as both have them
+fn shared_count(first: &[isize], second: &[isize]) -> usize {
+    let (mut first, mut second) = (first.iter().peekable(), second.iter().peekable());
+    let mut shared = 0;
+    while let (Some(left), Some(right)) = (first.peek(), second.peek()) {
+        match left.cmp(right) {
+            Ordering::Less => {
+                first.next();
+            }
+            Ordering::Greater => {
+                second.next();
+            }
+            Ordering::Equal => {
+                shared += 1;
+                first.next();
+                second.next();
+            }
+        }
+    }
+    shared
+}
+
+/// The pose that puts at least [`MIN_OVERLAP`] of scanner's beacons on top of placed ones
+fn find_pose(placed: &[Point], scanner: &Scanner, rotations: &[Rotation]) -> Option<ScannerPose> {
+    for rotation in rotations {
+        let mut offsets: HashMap<Point, usize> = HashMap::new();
+        for beacon in &scanner.beacons {
+            let rotated = rotation.apply(*beacon);
+            for known in placed {
+                let offset = [0, 1, 2].map(|axis| known[axis] - rotated[axis]);
+                let matches = offsets.entry(offset).or_insert(0);
+                *matches += 1;
+                if *matches >= MIN_OVERLAP {
+                    return Some(ScannerPose {
+                        rotation: *rotation,
+                        position: offset,
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Scanner>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Scanner>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Scanner>> {
+    parse_lines(input.lines())
+}
+
+/// Each scanner is a `--- scanner <n> ---` line followed by one `x,y,z` line per beacon.
+/// Scanners must be numbered in order from 0.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Scanner>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut scanners: Vec<Scanner> = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with("---") {
+            let expected = format!("--- scanner {} ---", scanners.len());
+            if line != expected {
+                return Err(ParseError::invalid(line_index, 0, line, expected));
+            }
+            scanners.push(Scanner {
+                beacons: Vec::new(),
+            });
+            continue;
+        }
+
+        let scanner = scanners
+            .last_mut()
+            .ok_or_else(|| ParseError::invalid(line_index, 0, line, "--- scanner 0 ---"))?;
+        scanner.beacons.push(parse_point(line_index, line)?);
+    }
+
+    Ok(scanners)
+}
+
+fn parse_point(line_index: usize, line: &str) -> ParseResult<Point> {
+    let invalid = || ParseError::invalid(line_index, 0, line, "a beacon x,y,z");
+    let mut coordinates = line.split(',').map(|coordinate| coordinate.trim().parse());
+    let mut point = [0; 3];
+    for axis in &mut point {
+        *axis = coordinates
+            .next()
+            .ok_or_else(invalid)?
+            .map_err(|_| invalid())?;
+    }
+    if coordinates.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok(point)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{align, parse_input, parse_str, Rotation, Scanner, ScannerPose};
+    use aoc_common::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_parse_input() {
+        let scanners = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(scanners.len(), 5);
+        assert_eq!(scanners[0].beacons[0], [404, -588, -901]);
+        assert_eq!(scanners[2].beacons.len(), 26);
+        assert_eq!(scanners[4].beacons.last(), Some(&[30, -46, -14]));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("--- scanner 0 ---\n1,2,3\n--- scanner 2 ---\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected --- scanner 1 ---, found \"--- scanner 2 ---\""
+        );
+        assert!(parse_str("1,2,3\n").is_err());
+        assert!(parse_str("--- scanner 0 ---\n1,2\n").is_err());
+        assert!(parse_str("--- scanner 0 ---\n1,2,3,4\n").is_err());
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation::IDENTITY);
+
+        let point = [1, 2, 3];
+        let turned: HashSet<_> = rotations
+            .iter()
+            .map(|rotation| rotation.apply(point))
+            .collect();
+        assert_eq!(turned.len(), 24);
+        assert!(
+            !turned.contains(&[-1, 2, 3]),
+            "mirror images are not rotations"
+        );
+        for rotation in rotations {
+            assert_eq!(rotation.inverse().apply(rotation.apply(point)), point);
+        }
+    }
+
+    #[test]
+    fn test_align_example() {
+        let scanners = parse_input("src/example_input.txt").unwrap();
+        let alignment = align(&scanners).unwrap();
+        assert_eq!(alignment.poses[0], ScannerPose::ORIGIN);
+        let positions: Vec<_> = alignment.poses.iter().map(|pose| pose.position).collect();
+        assert_eq!(
+            positions,
+            vec![
+                [0, 0, 0],
+                [68, -1246, -43],
+                [1105, -1205, 1229],
+                [-92, -2380, -20],
+                [-20, -1133, 1061]
+            ]
+        );
+        assert_eq!(alignment.beacons.len(), 79);
+        assert_eq!(alignment.max_scanner_distance(), 3621);
+    }
+
+    #[test]
+    fn test_align_generated() {
+        // Far more scanners than the example, each of them seeing every beacon in its range
+        let mut rng = Rng::new(1919);
+        let scanners = parse_str(&generate(&mut rng, 12)).unwrap();
+        let alignment = align(&scanners).unwrap();
+        assert_eq!(alignment.poses.len(), 12);
+
+        let beacons: HashSet<_> = alignment.beacons.iter().copied().collect();
+        for (scanner, pose) in scanners.iter().zip(&alignment.poses) {
+            let seen: HashSet<_> = scanner
+                .beacons
+                .iter()
+                .map(|beacon| pose.apply(*beacon))
+                .collect();
+            assert!(seen.is_subset(&beacons));
+            let in_range = beacons.iter().filter(|beacon| {
+                (0..3).all(|axis| (beacon[axis] - pose.position[axis]).abs() <= 1000)
+            });
+            assert_eq!(in_range.count(), seen.len());
+        }
+    }
+
+    #[test]
+    fn test_align_unreachable() {
+        let mut scanners = parse_input("src/example_input.txt").unwrap();
+        scanners.push(Scanner {
+            beacons: vec![[0, 0, 0], [1, 1, 1]],
+        });
+        let error = align(&scanners).unwrap_err();
+        assert_eq!(error.to_string(), "Scanner 5 overlaps none of the others");
+    }
+}
@@ -0,0 +1,47 @@
+//! Random sonar sweeps.  Depths wander up and down but mostly go deeper, like the real report.
+use aoc_common::Rng;
+
+/// depths readings, one per line
+pub fn generate(rng: &mut Rng, depths: usize) -> String {
+    let mut depth = rng.range(100..200);
+    let mut input = String::new();
+    for _ in 0..depths {
+        input.push_str(&depth.to_string());
+        input.push('\n');
+
+        depth = (depth + rng.range(0..40)).saturating_sub(10);
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real report
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 2000 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{count_window_increases, parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(1);
+        let depths = parse_str(&generate(&mut rng, 500)).unwrap();
+        assert_eq!(depths.len(), 500);
+
+        // Comparing the ends of windows one at a time has to agree with summing them
+        for window_len in [1, 2, 3, 7] {
+            let sums: Vec<usize> = depths
+                .windows(window_len)
+                .map(|window| window.iter().sum())
+                .collect();
+            let increases = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+            assert_eq!(count_window_increases(&depths, window_len), increases);
+        }
+        assert_eq!(part_one(&depths), count_window_increases(&depths, 1));
+        assert_eq!(part_two(&depths), count_window_increases(&depths, 3));
+    }
+}
@@ -1,135 +1,237 @@
-use anyhow::{Context, Result};
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::BufReader;
 use std::{io::BufRead, path::Path};
 
-pub fn parse_input<P>(input_path: P) -> Result<Vec<usize>>
+pub struct DayOne;
+
+impl Solution for DayOne {
+    const DAY: u8 = 1;
+    type Input = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input))
+    }
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<usize>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .flatten()
-        .map(|y| {
-            y.trim()
-                .parse::<usize>()
-                .context(format!("Failed to parse line->{:?}", y))
-        })
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<usize>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<usize>> {
+    parse_lines(input.lines())
+}
+
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<usize>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_index, y)| parse_depth(line_index, y.as_ref()))
         .collect()
 }
 
+fn parse_depth(line_index: usize, y: &str) -> ParseResult<usize> {
+    y.trim()
+        .parse::<usize>()
+        .map_err(|_| ParseError::invalid(line_index, 0, y, "a non-negative integer"))
+}
+
+/// Parse each depth as its line is read, so a report of any size can be streamed through
+/// [`count_window_increases_streaming`] without ever holding more than a window of it
+pub fn read_depths<R>(reader: R) -> impl Iterator<Item = Result<usize>>
+where
+    R: BufRead,
+{
+    reader.lines().enumerate().map(|(line_index, y)| {
+        let y = y.map_err(ParseError::from)?;
+        Ok(parse_depth(line_index, &y)?)
+    })
+}
+
 /// Return the number of elements that are larger than its previous element
 pub fn part_one(input: &[usize]) -> usize {
-    input
-        .iter()
-        .fold(
-            (0, usize::MAX),
-            |(increasing_count, previous_element), element| {
-                if *element > previous_element {
-                    (increasing_count + 1, *element)
-                } else {
-                    (increasing_count, *element)
-                }
-            },
-        )
-        .0
+    count_window_increases(input, 1)
 }
 
-/// Return the number of times the rolling window was greater than the previous window
-/// A rolling window W consists of 3 elements whose previous window contained the first 2 elements of W
-/// and the next window contains the last two elements of W.
-/// IMPORTANT input must contain elements who any 3 added together does not exceed the value of usize::MAX
-pub fn part_two(input: &[usize]) -> Result<usize> {
-    let mut prev_window = [usize::MAX; 3];
-    let mut prev_sum;
-    let mut curr_window = [usize::MAX; 3];
-    let mut curr_sum;
-
-    let first_four = input.iter().take(4).copied().collect::<Vec<usize>>();
-    if first_four.len() < 4 {
-        Ok(0)
-    } else {
-        prev_window[0] = first_four[0];
-        prev_window[1] = first_four[1];
-        prev_window[2] = first_four[2];
-        prev_sum = first_four[0] + first_four[1] + first_four[2];
-
-        curr_window[0] = first_four[1];
-        curr_window[1] = first_four[2];
-        curr_sum = first_four[1] + first_four[2];
-
-        let mut current_increasing = 0;
-        for x in input.iter().skip(3) {
-            curr_window[2] = *x;
-            curr_sum += *x;
-            if prev_sum < curr_sum {
-                current_increasing += 1;
-            }
+/// Return the number of times the rolling window of 3 was greater than the previous window
+pub fn part_two(input: &[usize]) -> usize {
+    count_window_increases(input, 3)
+}
+
+/// [`part_one`] over depths as they arrive.  Stops at the first error
+pub fn part_one_streaming<I>(depths: I) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<usize>>,
+{
+    count_window_increases_streaming(depths, 1)
+}
 
-            // Prepare for the next loop
-            prev_window.rotate_left(1);
-            prev_sum -= prev_window[2];
+/// [`part_two`] over depths as they arrive.  Stops at the first error
+pub fn part_two_streaming<I>(depths: I) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<usize>>,
+{
+    count_window_increases_streaming(depths, 3)
+}
 
-            prev_window[2] = curr_window[2];
-            prev_sum += prev_window[2];
-            assert_eq!(
-                prev_sum, curr_sum,
-                "prev_window:{:?} and curr_window:{:?} should be the same window at this time",
-                prev_window, curr_window
-            ); //
+/// Return the number of times the sum of a rolling window of window_len elements was greater
+/// than the sum of the previous window.
+/// Neighbouring windows share every element but the first of the previous window and the last
+/// of the current one, so only those two need comparing and the sums are never computed
+pub fn count_window_increases(input: &[usize], window_len: usize) -> usize {
+    input
+        .iter()
+        .zip(input.iter().skip(window_len))
+        .filter(|(leaving, entering)| entering > leaving)
+        .count()
+}
 
-            curr_sum -= curr_window[0];
-            curr_window.rotate_left(1);
+/// [`count_window_increases`] over depths as they arrive, only keeping the last window_len
+/// of them.  Stops at the first error
+pub fn count_window_increases_streaming<I>(depths: I, window_len: usize) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<usize>>,
+{
+    let mut window = VecDeque::with_capacity(window_len + 1);
+    let mut increases = 0;
+    for entering in depths {
+        let entering = entering?;
+        window.push_back(entering);
+        if window.len() > window_len {
+            let leaving = window.pop_front().expect("the window is not empty");
+            if entering > leaving {
+                increases += 1;
+            }
         }
-
-        Ok(current_increasing)
     }
+
+    Ok(increases)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_input, part_two};
+    use crate::{
+        count_window_increases, count_window_increases_streaming, parse_reader, parse_str,
+        part_one_streaming, part_two, part_two_streaming, read_depths,
+    };
+    use anyhow::anyhow;
 
     use super::part_one;
 
     #[test]
     fn test_part_two() {
         assert_eq!(
-            part_two(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]).unwrap(),
+            part_two(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
             5
         );
         assert_eq!(
-            part_two(&[199, 200, 208]).unwrap(),
+            part_two(&[199, 200, 208]),
             0,
             "Failed to return 0 with only 3 elements"
         );
         assert_eq!(
-            part_two(&[199, 200]).unwrap(),
+            part_two(&[199, 200]),
             0,
             "Failed to return 0 with only 2 elements"
         );
         assert_eq!(
-            part_two(&[199]).unwrap(),
+            part_two(&[199]),
             0,
             "Failed to return 0 with only 1 elements"
         );
+        assert_eq!(part_two(&[]), 0, "Failed to return 0 with only 0 elements");
+    }
+
+    #[test]
+    fn test_count_window_increases() {
+        let example = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(count_window_increases(&example, 1), part_one(&example));
+        assert_eq!(count_window_increases(&example, 3), 5);
+        assert_eq!(count_window_increases(&example, 5), 5);
         assert_eq!(
-            part_two(&[]).unwrap(),
+            count_window_increases(&example, 10),
             0,
-            "Failed to return 0 with only 0 elements"
+            "A single window has no previous window"
         );
+        assert_eq!(count_window_increases(&example, 11), 0);
+        assert_eq!(
+            count_window_increases(&example, 0),
+            0,
+            "Empty windows all sum to 0"
+        );
+    }
+
+    #[test]
+    fn test_streaming() {
+        let example = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        assert_eq!(
+            part_one_streaming(read_depths(example.as_bytes())).unwrap(),
+            7
+        );
+        assert_eq!(
+            part_two_streaming(read_depths(example.as_bytes())).unwrap(),
+            5
+        );
+        for window_len in 0..12 {
+            assert_eq!(
+                count_window_increases_streaming(read_depths(example.as_bytes()), window_len)
+                    .unwrap(),
+                count_window_increases(&parse_str(example).unwrap(), window_len),
+                "window_len {}",
+                window_len
+            );
+        }
+
+        // Far more depths than are ever held at once
+        let rising = (0..1_000_000).map(Ok);
+        assert_eq!(part_two_streaming(rising).unwrap(), 1_000_000 - 3);
     }
 
     #[test]
-    fn test_part_two_my_input() {
+    fn test_streaming_errors() {
+        let error = part_one_streaming(read_depths("199\n200\nabc\n".as_bytes())).unwrap_err();
         assert_eq!(
-            part_two(&parse_input("src/input.txt").unwrap()).unwrap(),
-            1235
+            error.to_string(),
+            "line 3, column 1: expected a non-negative integer, found \"abc\""
         );
+
+        // Nothing after the first error is read
+        let depths = vec![Ok(1), Err(anyhow!("disconnected")), Ok(2)].into_iter();
+        let mut depths = depths.peekable();
+        assert!(part_two_streaming(depths.by_ref()).is_err());
+        assert!(depths.peek().is_some());
     }
+
     #[test]
     fn test_part_one() {
         assert_eq!(
@@ -147,7 +249,10 @@ mod tests {
     }
 
     #[test]
-    fn part_one_my_input() {
-        assert_eq!(part_one(&parse_input("src/input.txt").unwrap()), 1195);
+    fn test_parse_str_and_reader() {
+        let input = "199\n200\n208\n";
+        assert_eq!(parse_str(input).unwrap(), vec![199, 200, 208]);
+        assert_eq!(parse_reader(input.as_bytes()).unwrap(), vec![199, 200, 208]);
+        assert_eq!(parse_str("199\nabc").unwrap_err().line(), Some(2));
     }
 }
@@ -0,0 +1,73 @@
+//! Random crab swarms.
+use aoc_common::Rng;
+
+/// crabs horizontal positions from 0 to furthest on a single comma separated line.  Most crabs
+/// are bunched towards the start like the real input
+pub fn generate(rng: &mut Rng, crabs: usize, furthest: usize) -> String {
+    let positions: Vec<String> = (0..crabs)
+        .map(|_| {
+            // The smaller of two picks leans towards 0
+            let position = rng.range(0..furthest + 1).min(rng.range(0..furthest + 1));
+            position.to_string()
+        })
+        .collect();
+
+    positions.join(",") + "\n"
+}
+
+/// A scale of 1 is about the size of the real swarm.  Only the number of crabs grows
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 1000 * scale, 2000)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{calculate_cost_part_one, calculate_cost_part_two, cheapest_alignment, parse_str};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(7);
+        let input = generate(&mut rng, 200, 300);
+        let mut positions: Vec<isize> = input
+            .trim()
+            .split(',')
+            .map(|position| position.parse().unwrap())
+            .collect();
+        let crabs = parse_str(&input).unwrap();
+
+        // Moving one step at a time is cheapest at the median
+        positions.sort_unstable();
+        let median = positions[positions.len() / 2];
+        let fuel: isize = positions
+            .iter()
+            .map(|position| (position - median).abs())
+            .sum();
+        assert_eq!(
+            cheapest_alignment(&crabs, calculate_cost_part_one)
+                .unwrap()
+                .0,
+            fuel
+        );
+
+        // Every position the slow way, crab by crab
+        let fuel = (0..=300)
+            .map(|target: isize| {
+                positions
+                    .iter()
+                    .map(|position| {
+                        let distance = (position - target).abs();
+                        distance * (distance + 1) / 2
+                    })
+                    .sum::<isize>()
+            })
+            .min();
+        assert_eq!(
+            cheapest_alignment(&crabs, calculate_cost_part_two)
+                .ok()
+                .map(|(fuel, _)| fuel),
+            fuel
+        );
+    }
+}
@@ -1,45 +1,57 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-pub fn part_one<P>(input_path: P) -> Result<(isize, isize)>
-where
-    P: AsRef<Path>,
-{
-    let input_parsed = parse_input(input_path)?;
+pub struct DaySeven;
 
-    let (min, max) = match (input_parsed.keys().min(), input_parsed.keys().max()) {
-        (Some(min), Some(max)) => (*min, *max),
-        (None, None) => return Err(anyhow!("Failed to find a min and max for the given input")),
-        (_, _) => {
-            panic!("If there was a min there should also be a max.  So this case should not exist")
-        }
-    };
+impl Solution for DaySeven {
+    const DAY: u8 = 7;
+    type Input = HashMap<isize, isize>;
+    type PartOne = isize;
+    type PartTwo = isize;
 
-    let mut costs = (min..=max).map(|x| (calculate_cost_part_one(&input_parsed, &x), x));
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
 
-    if let Some((mut current_min_cost, mut current_min_location)) = costs.next() {
-        for (next_min_cost, next_min_location) in costs {
-            if next_min_cost < current_min_cost {
-                current_min_cost = next_min_cost;
-                current_min_location = next_min_location;
-            }
-        }
-        Ok((current_min_cost, current_min_location))
-    } else {
-        Err(anyhow!("Failed to find a min cost"))
+    /// Only the fuel is the answer.  Where the crabs line up is dropped
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(cheapest_alignment(input, calculate_cost_part_one)?.0)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(cheapest_alignment(input, calculate_cost_part_two)?.0)
     }
 }
 
+pub fn part_one<P>(input_path: P) -> Result<(isize, isize)>
+where
+    P: AsRef<Path>,
+{
+    cheapest_alignment(&parse_input(input_path)?, calculate_cost_part_one)
+}
+
 pub fn part_two<P>(input_path: P) -> Result<(isize, isize)>
 where
     P: AsRef<Path>,
 {
-    let input_parsed = parse_input(input_path)?;
+    cheapest_alignment(&parse_input(input_path)?, calculate_cost_part_two)
+}
 
+/// Return the (cost, location) of the location which is cheapest for every crab to move to
+fn cheapest_alignment<F>(
+    input_parsed: &HashMap<isize, isize>,
+    cost_function: F,
+) -> Result<(isize, isize)>
+where
+    F: Fn(&HashMap<isize, isize>, &isize) -> isize,
+{
     let (min, max) = match (input_parsed.keys().min(), input_parsed.keys().max()) {
         (Some(min), Some(max)) => (*min, *max),
         (None, None) => return Err(anyhow!("Failed to find a min and max for the given input")),
@@ -48,7 +60,7 @@ where
         }
     };
 
-    let mut costs = (min..=max).map(|x| (calculate_cost_part_two(&input_parsed, &x), x));
+    let mut costs = (min..=max).map(|x| (cost_function(input_parsed, &x), x));
 
     if let Some((mut current_min_cost, mut current_min_location)) = costs.next() {
         for (next_min_cost, next_min_location) in costs {
@@ -90,23 +102,52 @@ fn calculate_cost_part_two(
         })
 }
 
-pub fn parse_input<P>(input_path: P) -> Result<HashMap<isize, isize>>
+pub fn parse_input<P>(input_path: P) -> ParseResult<HashMap<isize, isize>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
-    match reader.lines().flatten().next() {
+
+    parse_reader(BufReader::new(file))
+}
+
+/// Only the first line of reader is read
+pub fn parse_reader<R>(reader: R) -> ParseResult<HashMap<isize, isize>>
+where
+    R: BufRead,
+{
+    parse_line(reader.lines().next().transpose()?)
+}
+
+/// Only the first line of input is used
+pub fn parse_str(input: &str) -> ParseResult<HashMap<isize, isize>> {
+    parse_line(input.lines().next())
+}
+
+fn parse_line<S>(line: Option<S>) -> ParseResult<HashMap<isize, isize>>
+where
+    S: AsRef<str>,
+{
+    match line {
         Some(line) => {
+            let mut column_index = 0;
             let crab_locations: Vec<isize> = line
-                .trim()
+                .as_ref()
+                .trim_end()
                 .split(',')
                 .map(|crab_location| {
-                    crab_location
-                        .parse::<isize>()
-                        .expect("Only integer locations are allowed")
+                    let location_column = column_index;
+                    column_index += crab_location.len() + 1;
+                    crab_location.parse::<isize>().map_err(|_| {
+                        ParseError::invalid(
+                            0,
+                            location_column,
+                            crab_location,
+                            "an integer location",
+                        )
+                    })
                 })
-                .collect();
+                .collect::<ParseResult<_>>()?;
             let mut crab_costs_by_location = HashMap::new();
             for crab_location in crab_locations {
                 match crab_costs_by_location.entry(crab_location) {
@@ -119,13 +160,16 @@ where
 
             Ok(crab_costs_by_location)
         }
-        None => panic!("Input was empty"),
+        None => Err(ParseError::unexpected_end(
+            0,
+            "a comma separated list of crab locations",
+        )),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{calculate_cost_part_one, parse_input, part_one, part_two};
+    use crate::{calculate_cost_part_one, parse_input, parse_line, part_one, part_two};
     use std::collections::HashMap;
 
     #[test]
@@ -179,18 +223,19 @@ mod tests {
         assert_eq!(part_one("src/example_input.txt").unwrap(), (37, 2))
     }
 
-    #[test]
-    fn test_part_one_my_input() {
-        assert_eq!(part_one("src/input.txt").unwrap(), (337833, 331))
-    }
-
     #[test]
     fn test_part_two_example() {
         assert_eq!(part_two("src/example_input.txt").unwrap(), (168, 5))
     }
 
     #[test]
-    fn test_part_two_my_input() {
-        assert_eq!(part_two("src/input.txt").unwrap(), (96678050, 461))
+    fn test_parse_line_invalid() {
+        assert!(parse_line(None::<&str>).is_err());
+
+        let error = parse_line(Some("16,1,two")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected an integer location, found \"two\""
+        );
     }
 }
@@ -0,0 +1,55 @@
+//! Random target areas.
+use crate::{slowest_reaching, triangle, Target};
+use aoc_common::Rng;
+
+/// A target to the right of and below the launcher, like the real ones, around reach away.
+/// Some probe always stalls over the target's x range, so the highest throw is the one that
+/// falls through y = 0 just fast enough to reach its bottom row
+pub fn generate(rng: &mut Rng, reach: usize) -> String {
+    let reach = reach.max(8);
+    let stall_speed = slowest_reaching((reach / 2 + rng.range(0..reach / 2)) as isize);
+    let stop = triangle(stall_speed);
+    let width = rng.range(2..stall_speed as usize + 1);
+    let x_min = stop - rng.range(0..width + 1) as isize;
+
+    let y_min = -(reach as isize / 2 + rng.range(0..reach / 4 + 1) as isize);
+    let height = rng.range(5..reach / 4 + 6) as isize;
+    let target = Target {
+        x_min,
+        x_max: x_min + width as isize,
+        y_min,
+        y_max: (y_min + height).min(-1),
+    };
+
+    format!("{}\n", target)
+}
+
+/// A scale of 1 is about the size of the real target
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 200 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, part_two, triangle};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(17);
+        for _ in 0..20 {
+            let input = generate(&mut rng, 60);
+            let target = parse_str(&input).unwrap();
+            assert_eq!(target.to_string() + "\n", input);
+            assert!(target.x_min > 0 && target.y_max < 0);
+
+            assert_eq!(part_one(&target).unwrap(), triangle(-target.y_min - 1));
+            let count = (0..=target.x_max)
+                .flat_map(|x| (target.y_min..=-target.y_min).map(move |y| (x, y)))
+                .filter(|velocity| target.highest_hit(*velocity).is_some())
+                .count();
+            assert_eq!(part_two(&target).unwrap(), count);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+/// (x, y) with x growing to the right and y growing up, the way the puzzle has it
+pub type Velocity = (isize, isize);
+
+pub struct DaySeventeen;
+
+impl Solution for DaySeventeen {
+    const DAY: u8 = 17;
+    type Input = Target;
+    type PartOne = isize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+/// The trench the probe has to be in after some step.  Both ranges include their ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub x_min: isize,
+    pub x_max: isize,
+    pub y_min: isize,
+    pub y_max: isize,
+}
+
+impl Target {
+    pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+        (self.x_min..=self.x_max).contains(&x) && (self.y_min..=self.y_max).contains(&y)
+    }
+
+    /// Every velocity outside these ranges misses, worked out from the target rather than
+    /// searched for.
+    ///
+    /// A probe that starts faster than the far edge of the target is past it after one step,
+    /// and a target on one side needs at least the smallest speed whose drag still carries the
+    /// probe to the near edge.  Upwards, a probe comes back down through y = 0 at the speed it
+    /// went up with plus one, so going up faster than the target is deep skips it on the way
+    /// down.  The one exception is a target that holds y = 0: then the only thing that stops a
+    /// fast probe is leaving the target sideways, so there is no bound at all if the probe can
+    /// stall above it
+    pub fn velocity_bounds(&self) -> Result<(RangeInclusive<isize>, RangeInclusive<isize>)> {
+        let x_bounds = if self.x_min > 0 {
+            slowest_reaching(self.x_min)..=self.x_max
+        } else if self.x_max < 0 {
+            self.x_min..=-slowest_reaching(-self.x_max)
+        } else {
+            self.x_min..=self.x_max
+        };
+
+        let y_min = if self.y_min > 0 {
+            slowest_reaching(self.y_min)
+        } else {
+            self.y_min
+        };
+        let mut y_max = self.y_max.max(-self.y_min - 1);
+        if self.y_min <= 0 && self.y_max >= 0 {
+            let stalls = x_bounds.clone().any(|x_velocity| {
+                let stop = x_velocity.signum() * triangle(x_velocity.abs());
+                (self.x_min..=self.x_max).contains(&stop)
+            });
+            if stalls {
+                return Err(anyhow!(
+                    "The probe can stall above {}, so it can be thrown arbitrarily high",
+                    self
+                ));
+            }
+            // Coming back through y = 0 after 2 * y + 1 steps, which is only in the target
+            // while the probe is still moving sideways
+            y_max = y_max.max(self.x_min.abs().max(self.x_max.abs()));
+        }
+
+        Ok((x_bounds, y_min..=y_max))
+    }
+
+    /// The highest the probe gets when thrown at velocity, or None if it never lands in the
+    /// target after a step
+    pub fn highest_hit(&self, (mut x_velocity, mut y_velocity): Velocity) -> Option<isize> {
+        let (mut x, mut y) = (0, 0);
+        let mut highest = 0;
+        loop {
+            x += x_velocity;
+            y += y_velocity;
+            x_velocity -= x_velocity.signum();
+            y_velocity -= 1;
+            highest = highest.max(y);
+
+            if self.contains((x, y)) {
+                return Some(highest);
+            }
+            let falling_past = y < self.y_min && y_velocity <= 0;
+            let moving_away =
+                (x_velocity >= 0 && x > self.x_max) || (x_velocity <= 0 && x < self.x_min);
+            if falling_past || moving_away {
+                return None;
+            }
+        }
+    }
+
+    /// Every velocity that hits the target along with the highest the probe gets for it
+    pub fn hits(&self) -> Result<Vec<(Velocity, isize)>> {
+        let (x_bounds, y_bounds) = self.velocity_bounds()?;
+
+        Ok(x_bounds
+            .flat_map(|x_velocity| {
+                y_bounds.clone().filter_map(move |y_velocity| {
+                    let velocity = (x_velocity, y_velocity);
+                    self.highest_hit(velocity)
+                        .map(|highest| (velocity, highest))
+                })
+            })
+            .collect())
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "target area: x={}..{}, y={}..{}",
+            self.x_min, self.x_max, self.y_min, self.y_max
+        )
+    }
+}
+
+fn triangle(steps: isize) -> isize {
+    steps * (steps + 1) / 2
+}
+
+/// The smallest speed that drag still lets cover distance, which must be positive
+fn slowest_reaching(distance: isize) -> isize {
+    let mut speed = ((2.0 * distance as f64).sqrt() as isize).saturating_sub(1);
+    while triangle(speed) < distance {
+        speed += 1;
+    }
+    speed
+}
+
+/// The highest the probe can get while still hitting the target
+pub fn part_one(target: &Target) -> Result<isize> {
+    target
+        .hits()?
+        .into_iter()
+        .map(|(_, highest)| highest)
+        .max()
+        .ok_or_else(|| anyhow!("No velocity hits {}", target))
+}
+
+/// The number of distinct velocities that hit the target
+pub fn part_two(target: &Target) -> Result<usize> {
+    Ok(target.hits()?.len())
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Target>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+/// Only the first line of reader is read
+pub fn parse_reader<R>(reader: R) -> ParseResult<Target>
+where
+    R: BufRead,
+{
+    parse_line(reader.lines().next().transpose()?)
+}
+
+/// Only the first line of input is used
+pub fn parse_str(input: &str) -> ParseResult<Target> {
+    parse_line(input.lines().next())
+}
+
+/// `target area: x=<x1>..<x2>, y=<y1>..<y2>`.  Either end of a range may come first
+fn parse_line<S>(line: Option<S>) -> ParseResult<Target>
+where
+    S: AsRef<str>,
+{
+    const EXPECTED: &str = "target area: x=<x1>..<x2>, y=<y1>..<y2>";
+    let line = line.ok_or_else(|| ParseError::unexpected_end(0, EXPECTED))?;
+    let line = line.as_ref().trim_end();
+
+    let ranges = line
+        .strip_prefix("target area: x=")
+        .ok_or_else(|| ParseError::invalid(0, 0, line, EXPECTED))?;
+    let (x_range, y_range) = ranges
+        .split_once(", y=")
+        .ok_or_else(|| ParseError::invalid(0, 0, line, EXPECTED))?;
+    let x_column = line.len() - ranges.len();
+    let (x_min, x_max) = parse_range(x_column, x_range)?;
+    let (y_min, y_max) = parse_range(x_column + x_range.len() + ", y=".len(), y_range)?;
+
+    Ok(Target {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    })
+}
+
+/// `<from>..<to>` as (smallest, largest)
+fn parse_range(column_index: usize, range: &str) -> ParseResult<(isize, isize)> {
+    let invalid = || ParseError::invalid(0, column_index, range, "a range <from>..<to>");
+    let (from, to) = range.split_once("..").ok_or_else(invalid)?;
+    let from: isize = from.parse().map_err(|_| invalid())?;
+    let to: isize = to.parse().map_err(|_| invalid())?;
+
+    Ok((from.min(to), from.max(to)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, parse_str, part_one, part_two, Target};
+
+    /// Every velocity in a generous square, simulated, as (highest, count)
+    fn brute_force(target: &Target, limit: isize) -> (Option<isize>, usize) {
+        let highests: Vec<isize> = (-limit..=limit)
+            .flat_map(|x| (-limit..=limit).filter_map(move |y| target.highest_hit((x, y))))
+            .collect();
+        (highests.iter().copied().max(), highests.len())
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let target = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(
+            target,
+            Target {
+                x_min: 20,
+                x_max: 30,
+                y_min: -10,
+                y_max: -5
+            }
+        );
+        assert_eq!(target.to_string(), "target area: x=20..30, y=-10..-5");
+        assert_eq!(
+            parse_str("target area: x=30..20, y=-5..-10").unwrap(),
+            target
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("target area: x=20..30, y=-10..x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 26: expected a range <from>..<to>, found \"-10..x\""
+        );
+        assert!(parse_str("target area: x=20..30").is_err());
+        assert!(parse_str("").is_err());
+    }
+
+    #[test]
+    fn test_highest_hit() {
+        let target = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(target.highest_hit((7, 2)), Some(3));
+        assert_eq!(target.highest_hit((6, 3)), Some(6));
+        assert_eq!(target.highest_hit((9, 0)), Some(0));
+        assert_eq!(target.highest_hit((17, -4)), None);
+        assert_eq!(target.highest_hit((6, 9)), Some(45));
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let target = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_one(&target).unwrap(), 45);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let target = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(part_two(&target).unwrap(), 112);
+    }
+
+    #[test]
+    fn test_bounds_match_brute_force() {
+        for input in [
+            "target area: x=20..30, y=-10..-5",
+            "target area: x=-30..-20, y=-10..-5",
+            "target area: x=20..30, y=5..10",
+            "target area: x=-12..-11, y=3..4",
+            "target area: x=11..14, y=-5..5",
+            "target area: x=-5..5, y=-10..-5",
+            "target area: x=0..0, y=-3..-3",
+            "target area: x=3..3, y=7..7",
+        ] {
+            let target = parse_str(input).unwrap();
+            let (highest, count) = brute_force(&target, 60);
+            assert_eq!(part_one(&target).ok(), highest, "{}", input);
+            assert_eq!(part_two(&target).unwrap(), count, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_stalling_above_the_target() {
+        // Drag stops a probe thrown at x = 6 over x = 21, where it can fall straight back down
+        // through y = 0 however high it went
+        let target = parse_str("target area: x=20..30, y=-5..5").unwrap();
+        assert!(part_one(&target).is_err());
+        assert!(part_two(&target).is_err());
+        assert!(part_two(&parse_str("target area: x=-1..1, y=0..0").unwrap()).is_err());
+    }
+}
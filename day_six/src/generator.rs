@@ -0,0 +1,31 @@
+//! Random schools of lanternfish.
+use aoc_common::Rng;
+
+/// fish timers of 1 to 5, like the real input, on a single comma separated line
+pub fn generate(rng: &mut Rng, fish: usize) -> String {
+    let timers: Vec<String> = (0..fish).map(|_| rng.range(1..6).to_string()).collect();
+
+    timers.join(",") + "\n"
+}
+
+/// A scale of 1 is about the size of the real school
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 300 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_naive_matches_counting() {
+        let mut rng = Rng::new(6);
+        for fish in [1, 10, 100] {
+            let timers = parse_str(&generate(&mut rng, fish)).unwrap();
+            assert_eq!(timers.len(), fish);
+            assert_eq!(part_one(timers.clone()), part_two(timers, 80));
+        }
+    }
+}
@@ -1,5 +1,80 @@
+pub mod generator;
+
+use anyhow::Result;
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    const DAY: u8 = 6;
+    type Input = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input.clone()))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input.clone(), 256))
+    }
+}
+
+/// Collect the comma separated timers of every fish from the first line of input_path
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<usize>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+/// Only the first line of reader is read
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<usize>>
+where
+    R: BufRead,
+{
+    parse_line(reader.lines().next().transpose()?)
+}
+
+/// Only the first line of input is used
+pub fn parse_str(input: &str) -> ParseResult<Vec<usize>> {
+    parse_line(input.lines().next())
+}
+
+fn parse_line<S>(line: Option<S>) -> ParseResult<Vec<usize>>
+where
+    S: AsRef<str>,
+{
+    let line = match line {
+        Some(line) => line,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut column_index = 0;
+    line.as_ref()
+        .trim_end()
+        .split(',')
+        .map(|timer| {
+            let timer_column = column_index;
+            column_index += timer.len() + 1;
+            timer
+                .parse::<usize>()
+                .map_err(|_| ParseError::invalid(0, timer_column, timer, "a fish timer"))
+        })
+        .collect()
+}
+
 /// Every fish is the same so all we need to know is how many fish are on each day.
-fn part_two(input: Vec<usize>, num_days: usize) -> usize {
+pub fn part_two(input: Vec<usize>, num_days: usize) -> usize {
     let mut lantern_fish = [0; 9];
 
 
@@ -17,7 +92,7 @@ fn part_two(input: Vec<usize>, num_days: usize) -> usize {
 
 
 /// The naive approach to keep track of every fish individually and update them all individually.
-fn part_one(mut input: Vec<usize>) -> usize {
+pub fn part_one(mut input: Vec<usize>) -> usize {
     let mut new_fish = Vec::new();
     for _ in 0..80 {
         for fish in input.iter_mut() {
@@ -52,18 +127,6 @@ mod tests {
         assert_eq!(part_one(example_input), 5934);
     }
 
-    #[test]
-    fn test_my_input_part_one() {
-        let example_input = "5,1,1,3,1,1,5,1,2,1,5,2,5,1,1,1,4,1,1,5,1,1,4,1,1,1,3,5,1,1,1,1,1,1,1,1,1,4,4,4,1,1,1,1,1,4,1,1,1,1,1,5,1,1,1,4,1,1,1,1,1,3,1,1,4,1,4,1,1,2,3,1,1,1,1,4,1,2,2,1,1,1,1,1,1,3,1,1,1,1,1,2,1,1,1,1,1,1,1,4,4,1,4,2,1,1,1,1,1,4,3,1,1,1,1,2,1,1,1,2,1,1,3,1,1,1,2,1,1,1,3,1,3,1,1,1,1,1,1,1,1,1,3,1,1,1,1,3,1,1,1,1,1,1,2,1,1,2,3,1,2,1,1,4,1,1,5,3,1,1,1,2,4,1,1,2,4,2,1,1,1,1,1,1,1,2,1,1,1,1,1,1,1,1,4,3,1,2,1,2,1,5,1,2,1,1,5,1,1,1,1,1,1,2,2,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,4,1,1,1,1,1,3,1,1,5,1,1,1,1,5,1,4,1,1,1,4,1,3,4,1,4,1,1,1,1,1,1,1,1,1,3,5,1,3,1,1,1,1,4,1,5,3,1,1,1,1,1,5,1,1,1,2,2".split(",").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<usize>>();
-        assert_eq!(part_one(example_input), 394994);
-    }
-
-    #[test]
-    fn test_my_input_part_two() {
-        let example_input = "5,1,1,3,1,1,5,1,2,1,5,2,5,1,1,1,4,1,1,5,1,1,4,1,1,1,3,5,1,1,1,1,1,1,1,1,1,4,4,4,1,1,1,1,1,4,1,1,1,1,1,5,1,1,1,4,1,1,1,1,1,3,1,1,4,1,4,1,1,2,3,1,1,1,1,4,1,2,2,1,1,1,1,1,1,3,1,1,1,1,1,2,1,1,1,1,1,1,1,4,4,1,4,2,1,1,1,1,1,4,3,1,1,1,1,2,1,1,1,2,1,1,3,1,1,1,2,1,1,1,3,1,3,1,1,1,1,1,1,1,1,1,3,1,1,1,1,3,1,1,1,1,1,1,2,1,1,2,3,1,2,1,1,4,1,1,5,3,1,1,1,2,4,1,1,2,4,2,1,1,1,1,1,1,1,2,1,1,1,1,1,1,1,1,4,3,1,2,1,2,1,5,1,2,1,1,5,1,1,1,1,1,1,2,2,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,4,1,1,1,1,1,3,1,1,5,1,1,1,1,5,1,4,1,1,1,4,1,3,4,1,4,1,1,1,1,1,1,1,1,1,3,5,1,3,1,1,1,1,4,1,5,3,1,1,1,1,1,5,1,1,1,2,2".split(",").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<usize>>();
-        assert_eq!(part_two(example_input, 256), 1765974267455);
-    }
-
     #[test]
     fn test_example_part_two() {
         let example_input = vec![3, 4, 3, 1, 2];
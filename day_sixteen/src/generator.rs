@@ -0,0 +1,207 @@
+//! Random BITS transmissions.
+//!
+//! Transmissions are generated as a tree of [`Packet`]s which knows its own version sum and value,
+//! so a generated transmission comes with the answers to check a decoder against.
+use aoc_common::Rng;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Literal {
+        version: u8,
+        value: usize,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        /// Whether the sub-packets are counted (11 bit length) rather than measured in bits (15
+        /// bit length)
+        counted: bool,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    /// A packet with about packets packets in it, counting itself
+    pub fn random(rng: &mut Rng, packets: usize) -> Self {
+        let version = rng.range(0..8) as u8;
+        if packets <= 1 {
+            return Packet::Literal {
+                version,
+                value: rng.range(0..1 << 16),
+            };
+        }
+
+        let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+        let children = match type_id {
+            // Comparisons always have exactly two sub-packets
+            5..=7 => 2,
+            // Products of many packets overflow so keep them small
+            1 => rng.range(1..4),
+            _ => rng.range(1..6),
+        };
+        let remaining = packets - 1;
+        let mut sub_packets: Vec<Packet> = (0..children)
+            .map(|child| {
+                let share = remaining / children + usize::from(child < remaining % children);
+                Packet::random(rng, share)
+            })
+            .collect();
+        if type_id == 7 && rng.chance(0.5) {
+            // Equal packets are rare by chance so make some
+            sub_packets[1] = sub_packets[0].clone();
+        }
+
+        let mut counted = rng.chance(0.5);
+        let bits: usize = sub_packets.iter().map(|packet| packet.encode().len()).sum();
+        if bits >= 1 << 15 {
+            counted = true;
+        }
+
+        Packet::Operator {
+            version,
+            type_id,
+            counted,
+            sub_packets,
+        }
+    }
+
+    pub fn version_sum(&self) -> usize {
+        match self {
+            Packet::Literal { version, .. } => usize::from(*version),
+            Packet::Operator {
+                version,
+                sub_packets,
+                ..
+            } => usize::from(*version) + sub_packets.iter().map(Packet::version_sum).sum::<usize>(),
+        }
+    }
+
+    /// Sums and products wrap on overflow
+    pub fn value(&self) -> usize {
+        let (type_id, sub_packets) = match self {
+            Packet::Literal { value, .. } => return *value,
+            Packet::Operator {
+                type_id,
+                sub_packets,
+                ..
+            } => (*type_id, sub_packets),
+        };
+        let mut values = sub_packets.iter().map(Packet::value);
+        match type_id {
+            0 => values.fold(0, usize::wrapping_add),
+            1 => values.fold(1, usize::wrapping_mul),
+            2 => values.min().unwrap_or(0),
+            3 => values.max().unwrap_or(0),
+            _ => {
+                let (first, second) = (values.next(), values.next());
+                let holds = match type_id {
+                    5 => first > second,
+                    6 => first < second,
+                    _ => first == second,
+                };
+                usize::from(holds)
+            }
+        }
+    }
+
+    /// The packet as bits, without any padding
+    pub fn encode(&self) -> Vec<bool> {
+        let mut bits = Vec::new();
+        match self {
+            Packet::Literal { version, value } => {
+                push_number(&mut bits, usize::from(*version), 3);
+                push_number(&mut bits, 4, 3);
+
+                let groups = ((usize::BITS - value.leading_zeros()) as usize)
+                    .div_ceil(4)
+                    .max(1);
+                for group in (0..groups).rev() {
+                    bits.push(group != 0);
+                    push_number(&mut bits, value >> (group * 4) & 0xf, 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                type_id,
+                counted,
+                sub_packets,
+            } => {
+                push_number(&mut bits, usize::from(*version), 3);
+                push_number(&mut bits, usize::from(*type_id), 3);
+
+                let contents: Vec<bool> = sub_packets.iter().flat_map(Packet::encode).collect();
+                bits.push(*counted);
+                if *counted {
+                    push_number(&mut bits, sub_packets.len(), 11);
+                } else {
+                    push_number(&mut bits, contents.len(), 15);
+                }
+                bits.extend(contents);
+            }
+        }
+
+        bits
+    }
+
+    /// The packet as a transmission, padded with zeros to a whole number of hex digits
+    pub fn to_hex(&self) -> String {
+        let mut bits = self.encode();
+        bits.resize(bits.len().div_ceil(4) * 4, false);
+
+        bits.chunks(4)
+            .map(|nibble| {
+                let digit = nibble
+                    .iter()
+                    .fold(0, |digit, bit| digit * 2 + u32::from(*bit));
+                char::from_digit(digit, 16)
+                    .expect("four bits are one hex digit")
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+fn push_number(bits: &mut Vec<bool>, number: usize, width: usize) {
+    bits.extend((0..width).rev().map(|bit| number >> bit & 1 == 1));
+}
+
+/// A transmission of a single outer packet holding about packets packets
+pub fn generate(rng: &mut Rng, packets: usize) -> String {
+    Packet::random(rng, packets).to_hex() + "\n"
+}
+
+/// A scale of 1 is about the size of the real transmission
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 250 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::Packet;
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_encode_literal() {
+        let packet = Packet::Literal {
+            version: 6,
+            value: 2021,
+        };
+        assert_eq!(packet.to_hex(), "D2FE28");
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(16);
+        for packets in [1, 2, 10, 100, 250] {
+            let packet = Packet::random(&mut rng, packets);
+            let transmission = parse_str(&packet.to_hex()).unwrap();
+
+            assert_eq!(
+                part_one(transmission.clone()).unwrap(),
+                packet.version_sum()
+            );
+            assert_eq!(part_two(transmission).unwrap(), packet.value());
+        }
+    }
+}
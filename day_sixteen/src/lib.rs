@@ -1,8 +1,67 @@
+pub mod generator;
+
 use anyhow::anyhow;
 use anyhow::Result;
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DaySixteen;
+
+impl Solution for DaySixteen {
+    const DAY: u8 = 16;
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
 
-fn main() {
-    println!("Hello, world!");
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input.clone())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input.clone())
+    }
+}
+
+/// Collect the hex transmission from the first line of input_path
+pub fn parse_input<P>(input_path: P) -> ParseResult<String>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+/// Only the first line of reader is read
+pub fn parse_reader<R>(reader: R) -> ParseResult<String>
+where
+    R: BufRead,
+{
+    parse_line(reader.lines().next().transpose()?)
+}
+
+/// Only the first line of input is used
+pub fn parse_str(input: &str) -> ParseResult<String> {
+    parse_line(input.lines().next())
+}
+
+fn parse_line<S>(line: Option<S>) -> ParseResult<String>
+where
+    S: AsRef<str>,
+{
+    let line = line
+        .map(|line| line.as_ref().trim().to_string())
+        .ok_or_else(|| ParseError::unexpected_end(0, "a hexadecimal transmission"))?;
+    // Converting the whole line reports the first character that is not hex
+    hex_to_bit_vec(&line)?;
+
+    Ok(line)
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -41,10 +100,10 @@ fn bit_vec_to_usize(bit_vec: Vec<Bit>) -> Result<usize> {
     }
 }
 
-fn hex_to_bit_vec(input: String) -> Result<Vec<Bit>> {
+fn hex_to_bit_vec(input: &str) -> ParseResult<Vec<Bit>> {
     // It's not the right capacity, but eh
     let mut working_vector: Vec<Bit> = Vec::with_capacity(input.len());
-    for char in input.chars() {
+    for (column_index, char) in input.chars().enumerate() {
         match char {
             '0' => working_vector.append(&mut vec![Bit::Zero, Bit::Zero, Bit::Zero, Bit::Zero]),
             '1' => working_vector.append(&mut vec![Bit::Zero, Bit::Zero, Bit::Zero, Bit::One]),
@@ -62,7 +121,14 @@ fn hex_to_bit_vec(input: String) -> Result<Vec<Bit>> {
             'D' => working_vector.append(&mut vec![Bit::One, Bit::One, Bit::Zero, Bit::One]),
             'E' => working_vector.append(&mut vec![Bit::One, Bit::One, Bit::One, Bit::Zero]),
             'F' => working_vector.append(&mut vec![Bit::One, Bit::One, Bit::One, Bit::One]),
-            _ => panic!(),
+            _ => {
+                return Err(ParseError::invalid(
+                    0,
+                    column_index,
+                    char,
+                    "a hexadecimal digit 0-9 or A-F",
+                ))
+            }
         }
     }
 
@@ -99,8 +165,8 @@ where
     Ok(working_sum)
 }
 
-fn part_one(input_hex_string: String) -> Result<usize> {
-    let bit_vec = hex_to_bit_vec(input_hex_string)?;
+pub fn part_one(input_hex_string: String) -> Result<usize> {
+    let bit_vec = hex_to_bit_vec(&input_hex_string)?;
     if bit_vec.len() < 6 {
         return Err(anyhow!("Len must be greater than 6"));
     }
@@ -108,13 +174,13 @@ fn part_one(input_hex_string: String) -> Result<usize> {
     let mut bit_vec_iter = bit_vec.into_iter();
     let result = count_version_numbers(&mut bit_vec_iter)?;
     match result {
-        None => return Err(anyhow!("Not enough bits")),
+        None => Err(anyhow!("Not enough bits")),
         Some(result) => Ok(result),
     }
 }
 
-fn part_two(input_hex_string: String) -> Result<usize> {
-    let bit_vec = hex_to_bit_vec(input_hex_string)?;
+pub fn part_two(input_hex_string: String) -> Result<usize> {
+    let bit_vec = hex_to_bit_vec(&input_hex_string)?;
     if bit_vec.len() < 6 {
         return Err(anyhow!("Len must be greater than 6"));
     }
@@ -122,7 +188,7 @@ fn part_two(input_hex_string: String) -> Result<usize> {
     let mut bit_vec_iter = bit_vec.into_iter();
     let result = handle_packet(&mut bit_vec_iter)?;
     match result {
-        None => return Err(anyhow!("Not enough bits")),
+        None => Err(anyhow!("Not enough bits")),
         Some(result) => Ok(result),
     }
 }
@@ -379,7 +445,7 @@ mod tests {
     #[test]
     fn test_hex_to_bit_vec() {
         assert_eq!(
-            hex_to_bit_vec("D2FE28".to_string()).expect("Should return vec"),
+            hex_to_bit_vec("D2FE28").expect("Should return vec"),
             vec![
                 Bit::One,
                 Bit::One,
@@ -407,6 +473,10 @@ mod tests {
                 Bit::Zero
             ]
         );
+        assert_eq!(
+            hex_to_bit_vec("D2XE28").unwrap_err().to_string(),
+            "line 1, column 3: expected a hexadecimal digit 0-9 or A-F, found \"X\""
+        );
     }
 
     #[test]
@@ -490,12 +560,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn part_one_my_input() {
-        assert_eq!(part_one("C20D7900A012FB9DA43BA00B080310CE3643A0004362BC1B856E0144D234F43590698FF31D249F87B8BF1AD402389D29BA6ED6DCDEE59E6515880258E0040A7136712672454401A84CE65023D004E6A35E914BF744E4026BF006AA0008742985717440188AD0CE334D7700A4012D4D3AE002532F2349469100708010E8AD1020A10021B0623144A20042E18C5D88E6009CF42D972B004A633A6398CE9848039893F0650048D231EFE71E09CB4B4D4A00643E200816507A48D244A2659880C3F602E2080ADA700340099D0023AC400C30038C00C50025C00C6015AD004B95002C400A10038C00A30039C0086002B256294E0124FC47A0FC88ACE953802F2936C965D3005AC01792A2A4AC69C8C8CA49625B92B1D980553EE5287B3C9338D13C74402770803D06216C2A100760944D8200008545C8FB1EC80185945D9868913097CAB90010D382CA00E4739EDF7A2935FEB68802525D1794299199E100647253CE53A8017C9CF6B8573AB24008148804BB8100AA760088803F04E244480004323BC5C88F29C96318A2EA00829319856AD328C5394F599E7612789BC1DB000B90A480371993EA0090A4E35D45F24E35D45E8402E9D87FFE0D9C97ED2AF6C0D281F2CAF22F60014CC9F7B71098DFD025A3059200C8F801F094AB74D72FD870DE616A2E9802F800FACACA68B270A7F01F2B8A6FD6035004E054B1310064F28F1C00F9CFC775E87CF52ADC600AE003E32965D98A52969AF48F9E0C0179C8FE25D40149CC46C4F2FB97BF5A62ECE6008D0066A200D4538D911C401A87304E0B4E321005033A77800AB4EC1227609508A5F188691E3047830053401600043E2044E8AE0008443F84F1CE6B3F133005300101924B924899D1C0804B3B61D9AB479387651209AA7F3BC4A77DA6C519B9F2D75100017E1AB803F257895CBE3E2F3FDE014ABC".to_string()).expect("ok_result"),
-         960);
-    }
-
     #[test]
     fn test_part_two() {
         assert_eq!(part_two("C200B40A82".to_string()).expect("result ok"), 3);
@@ -516,10 +580,4 @@ mod tests {
             1
         );
     }
-
-    #[test]
-    fn part_two_my_input() {
-        assert_eq!(part_two("C20D7900A012FB9DA43BA00B080310CE3643A0004362BC1B856E0144D234F43590698FF31D249F87B8BF1AD402389D29BA6ED6DCDEE59E6515880258E0040A7136712672454401A84CE65023D004E6A35E914BF744E4026BF006AA0008742985717440188AD0CE334D7700A4012D4D3AE002532F2349469100708010E8AD1020A10021B0623144A20042E18C5D88E6009CF42D972B004A633A6398CE9848039893F0650048D231EFE71E09CB4B4D4A00643E200816507A48D244A2659880C3F602E2080ADA700340099D0023AC400C30038C00C50025C00C6015AD004B95002C400A10038C00A30039C0086002B256294E0124FC47A0FC88ACE953802F2936C965D3005AC01792A2A4AC69C8C8CA49625B92B1D980553EE5287B3C9338D13C74402770803D06216C2A100760944D8200008545C8FB1EC80185945D9868913097CAB90010D382CA00E4739EDF7A2935FEB68802525D1794299199E100647253CE53A8017C9CF6B8573AB24008148804BB8100AA760088803F04E244480004323BC5C88F29C96318A2EA00829319856AD328C5394F599E7612789BC1DB000B90A480371993EA0090A4E35D45F24E35D45E8402E9D87FFE0D9C97ED2AF6C0D281F2CAF22F60014CC9F7B71098DFD025A3059200C8F801F094AB74D72FD870DE616A2E9802F800FACACA68B270A7F01F2B8A6FD6035004E054B1310064F28F1C00F9CFC775E87CF52ADC600AE003E32965D98A52969AF48F9E0C0179C8FE25D40149CC46C4F2FB97BF5A62ECE6008D0066A200D4538D911C401A87304E0B4E321005033A77800AB4EC1227609508A5F188691E3047830053401600043E2044E8AE0008443F84F1CE6B3F133005300101924B924899D1C0804B3B61D9AB479387651209AA7F3BC4A77DA6C519B9F2D75100017E1AB803F257895CBE3E2F3FDE014ABC".to_string()).expect("ok_result"),
-         12301926782560);
-    }
 }
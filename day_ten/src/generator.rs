@@ -0,0 +1,112 @@
+//! Random navigation subsystems.
+use aoc_common::Rng;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Incomplete lines are never nested deeper than this so their scores fit in a usize
+const MAX_DEPTH: usize = 16;
+
+/// lines lines of about length characters each.  Roughly half are corrupted and the rest are
+/// incomplete, with an odd number of incomplete lines so that there is a middle score.  lines must
+/// be at least 1
+pub fn generate(rng: &mut Rng, lines: usize, length: usize) -> String {
+    let mut corrupted: Vec<bool> = (0..lines).map(|_| rng.chance(0.5)).collect();
+    if corrupted.iter().filter(|corrupted| !**corrupted).count() % 2 == 0 {
+        if let Some(last) = corrupted.last_mut() {
+            *last = !*last;
+        }
+    }
+
+    corrupted
+        .into_iter()
+        .map(|corrupted| random_line(rng, length, corrupted).0 + "\n")
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real subsystem
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 100 * scale, 100)
+}
+
+/// The line along with what checking it should find: the characters that complete it, or the
+/// first illegal character if it is corrupted
+fn random_line(rng: &mut Rng, length: usize, corrupted: bool) -> (String, Result<Vec<char>, char>) {
+    let mut line = String::with_capacity(length);
+    // The closing characters that are still owed, innermost last
+    let mut open = Vec::new();
+    let corrupt_at = corrupted.then(|| rng.range(0..length.max(1)));
+
+    for index in 0..length.max(1) {
+        if Some(index) == corrupt_at {
+            if open.is_empty() {
+                let (opening, closing) = *rng.choose(&PAIRS);
+                line.push(opening);
+                open.push(closing);
+            }
+            let expected = open.last().copied();
+            let illegal = loop {
+                let (_, closing) = *rng.choose(&PAIRS);
+                if Some(closing) != expected {
+                    break closing;
+                }
+            };
+            line.push(illegal);
+
+            // Anything can follow the first illegal character
+            for _ in index + 1..length {
+                let (opening, closing) = *rng.choose(&PAIRS);
+                line.push(if rng.chance(0.5) { opening } else { closing });
+            }
+            return (line, Err(illegal));
+        }
+
+        if open.is_empty() || (open.len() < MAX_DEPTH && rng.chance(0.55)) {
+            let (opening, closing) = *rng.choose(&PAIRS);
+            line.push(opening);
+            open.push(closing);
+        } else {
+            line.extend(open.pop());
+        }
+    }
+
+    if open.is_empty() {
+        let (opening, closing) = *rng.choose(&PAIRS);
+        line.push(opening);
+        open.push(closing);
+    }
+    open.reverse();
+    (line, Ok(open))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{generate, random_line};
+    use crate::{parse_str, part_one, part_two, validate_line};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_random_line() {
+        let mut rng = Rng::new(10);
+        for length in [1, 2, 10, 100] {
+            for corrupted in [false, true] {
+                let (line, expected) = random_line(&mut rng, length, corrupted);
+                assert_eq!(validate_line(&line), expected, "{}", line);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(10);
+        let lines = parse_str(&generate(&mut rng, 101, 80)).unwrap();
+        assert_eq!(lines.len(), 101);
+
+        let incomplete = lines
+            .iter()
+            .filter(|line| validate_line(line).is_ok())
+            .count();
+        assert_eq!(incomplete % 2, 1);
+        assert!(part_one(&lines) > 0);
+        assert!(part_two(&lines).unwrap() > 0);
+    }
+}
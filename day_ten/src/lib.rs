@@ -1,3 +1,80 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTen;
+
+impl Solution for DayTen {
+    const DAY: u8 = 10;
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input)
+    }
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<String>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<String>> {
+    parse_lines(input.lines())
+}
+
+/// Lines may only contain the opening and closing characters of chunks
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<String>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let line = line.as_ref().trim();
+            match line
+                .chars()
+                .enumerate()
+                .find(|(_, x)| !"()[]{}<>".contains(*x))
+            {
+                Some((column_index, x)) => Err(ParseError::invalid(
+                    line_index,
+                    column_index,
+                    x,
+                    "one of ()[]{}<>",
+                )),
+                None => Ok(line.to_string()),
+            }
+        })
+        .collect()
+}
+
 fn validate_line(line: &str) -> Result<Vec<char>, char> {
     let mut stack: Vec<char> = Vec::new();
     for current_char in line.trim().chars() {
@@ -34,13 +111,10 @@ pub fn part_one(input: &[String]) -> usize {
     })
 }
 
-pub fn part_two(input: &[String]) -> usize {
-    let incomplete_lines: Vec<Vec<char>> = input
-        .iter()
-        .map(|line| validate_line(line))
-        .filter(|result| result.is_ok())
-        .map(|x| x.unwrap())
-        .collect();
+/// The middle completion score of the incomplete lines.  Fails if there are none
+pub fn part_two(input: &[String]) -> Result<usize> {
+    let incomplete_lines: Vec<Vec<char>> =
+        input.iter().flat_map(|line| validate_line(line)).collect();
 
     let mut scores = Vec::with_capacity(incomplete_lines.len());
 
@@ -59,17 +133,16 @@ pub fn part_two(input: &[String]) -> usize {
     }
 
     scores.sort_unstable();
-    *scores
+    scores
         // floor is used instead of ceil becuase vectors start at index 0
         .get(f64::floor(scores.len() as f64 / 2.0) as usize)
-        .unwrap()
+        .copied()
+        .ok_or_else(|| anyhow!("There are no incomplete lines to score"))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part_one, part_two, validate_line};
-    use std::fs::File;
-    use std::io::{BufRead, BufReader};
+    use crate::{parse_str, part_one, part_two, validate_line};
 
     #[test]
     fn it_works() {
@@ -79,31 +152,23 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        let example_input:Vec<String> = "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n<{([{{}}[<[[[<>{}]]]>[]]".split('\n').map(|x|x.to_string()).collect();
+        let example_input = parse_str("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n<{([{{}}[<[[[<>{}]]]>[]]").unwrap();
         assert_eq!(part_one(&example_input), 26397);
     }
 
-    #[test]
-    fn test_part_one_my_input() {
-        let file = File::open("src/input.txt").unwrap();
-        let reader = BufReader::new(file);
-        let my_input: Vec<String> = reader.lines().flatten().collect();
-        assert_eq!(part_one(&my_input), 316851);
-    }
-
 
     #[test]
-    fn test_part_two_my_input() {
-        let file = File::open("src/input.txt").unwrap();
-        let reader = BufReader::new(file);
-        let my_input: Vec<String> = reader.lines().flatten().collect();
-        assert_eq!(part_two(&my_input), 316851);
+    fn test_part_two_example() {
+        let example_input = parse_str("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n<{([{{}}[<[[[<>{}]]]>[]]").unwrap();
+        assert_eq!(part_two(&example_input).unwrap(), 288957);
     }
 
     #[test]
-    fn test_part_two_example() {
-        let example_input:Vec<String> = "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n<{([{{}}[<[[[<>{}]]]>[]]".split('\n').map(|x|x.to_string()).collect();
-        assert_eq!(part_two(&example_input), 288957);
+    fn test_part_two_no_incomplete_lines() {
+        assert!(part_two(&[]).is_err());
+        let corrupted = parse_str("{([(<{}[<>[]}>{[]{[(<()>\n[[<[([]))<([[{}[[()]]]\n").unwrap();
+        let error = part_two(&corrupted).unwrap_err();
+        assert_eq!(error.to_string(), "There are no incomplete lines to score");
     }
 
     #[test]
@@ -0,0 +1,119 @@
+//! Random transparent paper.
+//!
+//! The paper is built backwards: a random code is drawn on a small sheet which is then unfolded
+//! again and again, so every fold lands exactly in the middle and never on a dot.
+use crate::{Dots, Operation};
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+/// dots and fold instructions that fold down to a width by height code.  Each of the folds
+/// alternates direction at random like the real instructions do
+pub fn generate(rng: &mut Rng, width: usize, height: usize, folds: usize) -> String {
+    let (sheets, operations) = unfold(rng, width, height, folds);
+    let mut dots: Vec<(isize, isize)> = sheets[0].iter().copied().collect();
+    rng.shuffle(&mut dots);
+
+    let mut input = String::new();
+    for (x, y) in dots {
+        input.push_str(&format!("{},{}\n", x, y));
+    }
+    input.push('\n');
+    for operation in operations {
+        match operation {
+            Operation::FoldX(line) => input.push_str(&format!("fold along x={}\n", line)),
+            Operation::FoldY(line) => input.push_str(&format!("fold along y={}\n", line)),
+        }
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real paper.  The code gets wider as it scales
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 40 * scale, 6, 12)
+}
+
+/// The dots on the sheet after each fold, starting with the unfolded sheet, and the folds that
+/// get from one to the next
+fn unfold(rng: &mut Rng, width: usize, height: usize, folds: usize) -> (Vec<Dots>, Vec<Operation>) {
+    let mut code: Dots = HashSet::new();
+    while code.is_empty() {
+        for x in 0..width.max(1) {
+            for y in 0..height.max(1) {
+                if rng.chance(0.4) {
+                    code.insert((x as isize, y as isize));
+                }
+            }
+        }
+    }
+
+    let mut sheets = vec![code];
+    let mut operations = Vec::with_capacity(folds);
+    let (mut width, mut height) = (width.max(1), height.max(1));
+    for _ in 0..folds {
+        let fold_x = rng.chance(0.5);
+        let line = if fold_x { width } else { height } as isize;
+
+        let mut unfolded = HashSet::new();
+        for (x, y) in sheets.last().expect("starts with the code") {
+            let mirrored = if fold_x {
+                (2 * line - x, *y)
+            } else {
+                (*x, 2 * line - y)
+            };
+            // Mostly one side or the other, sometimes both
+            let (keep, mirror) = match rng.range(0..10) {
+                0..=4 => (true, false),
+                5..=8 => (false, true),
+                _ => (true, true),
+            };
+            if keep {
+                unfolded.insert((*x, *y));
+            }
+            if mirror {
+                unfolded.insert(mirrored);
+            }
+        }
+
+        if fold_x {
+            operations.push(Operation::FoldX(width));
+            width = 2 * width + 1;
+        } else {
+            operations.push(Operation::FoldY(height));
+            height = 2 * height + 1;
+        }
+        sheets.push(unfolded);
+    }
+
+    sheets.reverse();
+    operations.reverse();
+    (sheets, operations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{generate, unfold};
+    use crate::{fold, parse_str, part_one};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_folds_undo_unfolding() {
+        let mut rng = Rng::new(13);
+        let (sheets, operations) = unfold(&mut rng, 8, 5, 6);
+
+        let mut dots = sheets[0].clone();
+        for (operation, expected) in operations.into_iter().zip(&sheets[1..]) {
+            dots = fold(dots, operation).unwrap();
+            assert_eq!(&dots, expected);
+        }
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(13);
+        let (dots, operations) = parse_str(&generate(&mut rng, 40, 6, 12)).unwrap();
+        assert_eq!(operations.len(), 12);
+        assert!(dots.iter().all(|(x, y)| *x >= 0 && *y >= 0));
+        assert!(part_one(dots, operations).unwrap() > 0);
+    }
+}
@@ -1,156 +1,257 @@
+pub mod generator;
+pub mod visualize;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Operation {
     FoldY(usize),
     FoldX(usize),
 }
 
-pub fn parse_input<P>(input_path: P) -> Result<(HashSet<(isize, isize)>, Vec<Operation>)>
+pub type Dots = HashSet<(isize, isize)>;
+
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    const DAY: u8 = 13;
+    type Input = (Dots, Vec<Operation>);
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input.0.clone(), input.1.clone())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input.0.clone(), input.1.clone())
+    }
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<(Dots, Vec<Operation>)>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
-    let mut lines_iter = reader.lines().flatten();
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<(Dots, Vec<Operation>)>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<(Dots, Vec<Operation>)> {
+    parse_lines(input.lines())
+}
+
+fn parse_lines<I>(lines: I) -> ParseResult<(Dots, Vec<Operation>)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut lines_iter = lines.into_iter().enumerate();
     let mut location_set = HashSet::new();
+    let mut location_lines = Vec::new();
     let mut operation_vector = Vec::new();
+    let mut next_line_index = 0;
 
     // Process the coordinates
-    for line in &mut lines_iter {
-        if line.trim().is_empty() {
+    for (line_index, line) in &mut lines_iter {
+        next_line_index = line_index + 1;
+        let line = line.as_ref().trim();
+        if line.is_empty() {
             break;
         }
 
-        let coordinate: Vec<isize> = line
-            .trim()
-            .split(',')
-            .map(|x| {
-                x.parse::<isize>()
-                    .expect("coordinate line must be in format isize,isize")
-            })
-            .collect();
-        if coordinate.len() != 2 {
-            return Err(anyhow!(
-                "Coordinate should be in  format isize,isize.  Got {:?}",
-                line
-            ));
-        }
-        location_set.insert((coordinate[0], coordinate[1]));
+        let coordinate = line
+            .split_once(',')
+            .and_then(|(x, y)| Some((x.parse::<isize>().ok()?, y.parse::<isize>().ok()?)));
+        match coordinate {
+            Some((x, _)) if x < 0 => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    x.to_string(),
+                    "x of at least 0",
+                ))
+            }
+            Some((x, y)) if y < 0 => {
+                let column_index = x.to_string().len() + 1;
+                return Err(ParseError::invalid(
+                    line_index,
+                    column_index,
+                    y.to_string(),
+                    "y of at least 0",
+                ));
+            }
+            Some(coordinate) => {
+                location_set.insert(coordinate);
+                location_lines.push((line_index, coordinate));
+            }
+            None => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    "a coordinate of the form isize,isize",
+                ))
+            }
+        };
     }
 
     // Process the operations
 
-    for line in lines_iter {
-        let line_split: Vec<&str> = line.split('=').collect();
-        match line_split.len() {
-            2 => {
-                let value: usize = line_split.get(1).unwrap().parse()?;
-                match line_split.get(0).unwrap().chars().last().unwrap() {
-                    'y' => operation_vector.push(Operation::FoldY(value)),
-                    'x' => operation_vector.push(Operation::FoldX(value)),
-                    _ => {
-                        return Err(anyhow!(
-                            "Failed to determind fold x or y from line {:?}",
-                            line
-                        ))
-                    }
-                };
-            }
-            _ => {
-                return Err(anyhow!("Failed to pull operation from line {:?}", line));
+    for (line_index, line) in lines_iter {
+        next_line_index = line_index + 1;
+        let line = line.as_ref().trim();
+        let operation = match line.split_once('=') {
+            Some(("fold along y", value)) => value.parse().ok().map(Operation::FoldY),
+            Some(("fold along x", value)) => value.parse().ok().map(Operation::FoldX),
+            _ => None,
+        };
+        match operation {
+            Some(operation) => operation_vector.push(operation),
+            None => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    "fold along x=N or fold along y=N",
+                ))
             }
         };
     }
 
+    // Part one needs a first fold to make
+    if operation_vector.is_empty() {
+        return Err(ParseError::unexpected_end(
+            next_line_index,
+            "fold along x=N or fold along y=N",
+        ));
+    }
+
+    // A dot on a fold line would have nowhere to go
+    for (line_index, (x, y)) in location_lines {
+        for operation in &operation_vector {
+            let on_fold_line = match operation {
+                Operation::FoldX(fold_line) => x as usize == *fold_line,
+                Operation::FoldY(fold_line) => y as usize == *fold_line,
+            };
+            if on_fold_line {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    format!("{},{}", x, y),
+                    format!("a dot off the fold line of {:?}", operation),
+                ));
+            }
+        }
+    }
+
     Ok((location_set, operation_vector))
 }
 
-fn fold(input_set: HashSet<(isize, isize)>, fold_operation: Operation) -> HashSet<(isize, isize)> {
+/// Fails if a dot lies on the fold line, which an earlier fold can leave there
+fn fold(
+    input_set: HashSet<(isize, isize)>,
+    fold_operation: Operation,
+) -> Result<HashSet<(isize, isize)>> {
     input_set
         .into_iter()
         .map(|(x, y)| match fold_operation {
             Operation::FoldX(fold_line) => {
                 let fold_line = fold_line as isize;
                 match x.cmp(&fold_line) {
-                    Ordering::Greater => (fold_line - (x - fold_line), y),
-                    Ordering::Less => (x, y),
-                    Ordering::Equal => panic!("No dot should appear on a fold_line"),
+                    Ordering::Greater => Ok((fold_line - (x - fold_line), y)),
+                    Ordering::Less => Ok((x, y)),
+                    Ordering::Equal => Err(anyhow!("The dot {},{} is on the fold line", x, y)),
                 }
             }
             Operation::FoldY(fold_line) => {
                 let fold_line = fold_line as isize;
                 match y.cmp(&fold_line) {
-                    Ordering::Greater => (x, fold_line - (y - fold_line)),
-                    Ordering::Less => (x, y),
-                    Ordering::Equal => panic!("No dot should appear on a fold_line"),
+                    Ordering::Greater => Ok((x, fold_line - (y - fold_line))),
+                    Ordering::Less => Ok((x, y)),
+                    Ordering::Equal => Err(anyhow!("The dot {},{} is on the fold line", x, y)),
                 }
             }
         })
         .collect()
 }
-pub fn part_one(input_set: HashSet<(isize, isize)>, operations: Vec<Operation>) -> usize {
-    fold(
-        input_set,
-        operations
-            .into_iter()
-            .next()
-            .expect("operations cannot be empty"),
-    )
-    .len()
+pub fn part_one(input_set: HashSet<(isize, isize)>, operations: Vec<Operation>) -> Result<usize> {
+    let first = operations
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("There is no fold to make"))?;
+    Ok(fold(input_set, first)?.len())
 }
 
-pub fn part_two(mut input_set: HashSet<(isize, isize)>, operations: Vec<Operation>) -> String {
+pub fn part_two(
+    mut input_set: HashSet<(isize, isize)>,
+    operations: Vec<Operation>,
+) -> Result<String> {
     for operation in operations {
-        input_set = fold(input_set, operation);
+        log::debug!("folding {} dots along {:?}", input_set.len(), operation);
+        input_set = fold(input_set, operation)?;
+    }
+    // A fold line past the middle of the paper folds dots beyond the top or left edge
+    if let Some((x, y)) = input_set.iter().find(|(x, y)| *x < 0 || *y < 0) {
+        return Err(anyhow!(
+            "The dot {},{} is folded past the edge of the paper",
+            x,
+            y
+        ));
     }
 
     let max_y = *input_set
         .iter()
         .map(|(_x, y)| y)
         .max()
-        .or(Some(&0))
-        .unwrap()
+        .unwrap_or(&0)
         + 1;
     let max_x = *input_set
         .iter()
         .map(|(x, _y)| x)
         .max()
-        .or(Some(&0))
-        .unwrap()
+        .unwrap_or(&0)
         + 1;
 
-    // what I should probably do is shift all of them so that they are all positive, but that's another day
-    assert!(max_y > 0);
-    assert!(max_x > 0);
     let mut final_vec = vec![vec!["."; max_y as usize]; max_x as usize];
-    dbg!((max_x, max_y));
-    dbg!(&input_set);
+    log::trace!("{} by {} code from {:?}", max_x, max_y, input_set);
 
     for (x, y) in input_set
         .into_iter()
         .map(|(x, y)| (x as usize, y as usize))
     {
-        *final_vec.get_mut(x).unwrap().get_mut(y).unwrap() = "#";
+        final_vec[x][y] = "#";
     }
 
-    final_vec
+    Ok(final_vec
         .into_iter()
         .fold(String::new(), |final_string, line| {
             final_string + &line.into_iter().fold(String::new(), |acc, char| acc + char) + "\n"
-        })
+        }))
 }
 #[cfg(test)]
 mod tests {
 
     use std::collections::HashSet;
 
-    use crate::{parse_input, part_one, part_two, Operation};
+    use crate::{parse_input, parse_str, part_one, part_two, Operation};
 
     #[test]
     fn it_works() {
@@ -195,37 +296,59 @@ mod tests {
     }
 
     #[test]
-    fn test_part_one_example() {
-        let example =
-            parse_input("src/example_input.txt").expect("Expected src/example_input.txt to exist");
+    fn test_parse_without_folds() {
+        let error = parse_str("6,10\n0,14\n\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4: input ended, expected fold along x=N or fold along y=N"
+        );
+        assert!(parse_str("6,10\n0,14\n").is_err());
+        assert!(parse_str("").is_err());
+    }
 
-        let actual_result = part_one(example.0, example.1);
-        assert_eq!(actual_result, 17);
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("-1,0\n3,3\n\nfold along x=5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected x of at least 0, found \"-1\""
+        );
+        let error = parse_str("5,0\n3,3\n\nfold along x=5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a dot off the fold line of FoldX(5), found \"5,0\""
+        );
+        assert!(parse_str("3,-3\n\nfold along x=5").is_err());
+        assert!(parse_str("3,3\n\nfold along x=1\nfold along y=3").is_err());
     }
 
     #[test]
-    fn test_part_one_my_input() {
-        let my_input = parse_input("src/input.txt").expect("Expected src/input.txt to exist");
+    fn test_fold_errors() {
+        // After the first fold the dot lands on the second fold line
+        let (dots, operations) = parse_str("7,0\n\nfold along x=5\nfold along x=3").unwrap();
+        assert!(part_two(dots, operations).is_err());
 
-        let actual_result = part_one(my_input.0, my_input.1);
-        assert_eq!(actual_result, 790);
+        // Folding at x=1 moves x=4 past the left edge
+        let (dots, operations) = parse_str("4,0\n\nfold along x=1").unwrap();
+        assert_eq!(part_one(dots.clone(), operations.clone()).unwrap(), 1);
+        assert!(part_two(dots, operations).is_err());
     }
 
     #[test]
-    fn part_two_example() {
+    fn test_part_one_example() {
         let example =
             parse_input("src/example_input.txt").expect("Expected src/example_input.txt to exist");
 
-        let actual_result = part_two(example.0, example.1);
-        assert_eq!(actual_result, "#####\n#...#\n#...#\n#...#\n#####\n");
+        let actual_result = part_one(example.0, example.1).unwrap();
+        assert_eq!(actual_result, 17);
     }
 
     #[test]
-    fn part_two_my_input() {
-        let my_input = parse_input("src/input.txt").expect("Expected src/input.txt to exist");
+    fn part_two_example() {
+        let example =
+            parse_input("src/example_input.txt").expect("Expected src/example_input.txt to exist");
 
-        let actual_result = part_two(my_input.0, my_input.1);
-        // Even splitting the lines this is hard to read backwards
-        assert_eq!(actual_result, "######\n#..#..\n#..#..\n.##...\n......\n.####.\n#....#\n#..#.#\n.#.###\n......\n######\n..#...\n..#...\n######\n......\n#...##\n#..#.#\n#.#..#\n##...#\n......\n######\n#.#..#\n#.#..#\n.#.##.\n......\n######\n#.#...\n#.#...\n#.....\n......\n....#.\n.....#\n#....#\n#####.\n......\n.####.\n#....#\n#....#\n.#..#.\n");
+        let actual_result = part_two(example.0, example.1).unwrap();
+        assert_eq!(actual_result, "#####\n#...#\n#...#\n#...#\n#####\n");
     }
 }
@@ -0,0 +1,72 @@
+//! Images of the paper as it is folded.
+use crate::{fold, parse_str, Dots, Operation};
+use anyhow::Result;
+use aoc_grid::Grid;
+use aoc_image::{Frames, Image};
+
+/// Write a frame of the unfolded paper and then one after each fold.  The paper shrinks with every
+/// fold, so each frame is smaller than the one before
+pub fn folds(dots: &Dots, operations: &[Operation], frames: &mut Frames) -> Result<()> {
+    let mut width = dots.iter().map(|(x, _y)| x + 1).max().unwrap_or(0) as usize;
+    let mut height = dots.iter().map(|(_x, y)| y + 1).max().unwrap_or(0) as usize;
+    let mut dots = dots.clone();
+    frames.push(&render(&dots, width, height))?;
+
+    for operation in operations {
+        match operation {
+            Operation::FoldX(fold_line) => width = *fold_line,
+            Operation::FoldY(fold_line) => height = *fold_line,
+        }
+        dots = fold(dots, operation.clone())?;
+        frames.push(&render(&dots, width, height))?;
+    }
+
+    Ok(())
+}
+
+/// Parse input and write the frames of [`folds`]
+pub fn visualize(input: &str, frames: &mut Frames) -> Result<()> {
+    let (dots, operations) = parse_str(input)?;
+    folds(&dots, &operations, frames)
+}
+
+/// Dots are black on a white sheet with x across and y down
+fn render(dots: &Dots, width: usize, height: usize) -> Image {
+    let mut paper = Grid::new(width, height, false);
+    for (x, y) in dots {
+        if let Some(dot) = paper.get_mut((*y as usize, *x as usize)) {
+            *dot = true;
+        }
+    }
+
+    Image::Bitmap(paper)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_input;
+    use crate::visualize::folds;
+    use aoc_image::Frames;
+
+    #[test]
+    fn test_folds() {
+        let (dots, operations) = parse_input("src/example_input.txt").unwrap();
+        let directory =
+            std::env::temp_dir().join(format!("day_thirteen_folds_{}", std::process::id()));
+        let mut frames = Frames::create(&directory, "paper").unwrap();
+        folds(&dots, &operations, &mut frames).unwrap();
+        assert_eq!(frames.len(), 3);
+
+        let unfolded = std::fs::read(directory.join("paper_00000.pbm")).unwrap();
+        assert!(unfolded.starts_with(b"P4\n11 15\n"));
+        // The example folds up into a square
+        let folded = std::fs::read(directory.join("paper_00002.pbm")).unwrap();
+        let mut expected = b"P4\n5 7\n".to_vec();
+        expected.extend([
+            0b11111000, 0b10001000, 0b10001000, 0b10001000, 0b11111000, 0, 0,
+        ]);
+        assert_eq!(folded, expected);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
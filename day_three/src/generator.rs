@@ -0,0 +1,73 @@
+//! Random diagnostic reports.
+use crate::life_support_rating;
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+/// numbers distinct binary numbers of bits digits each.
+///
+/// Narrowing down the life support ratings can filter out every number, so reports are drawn
+/// again until both ratings can be found.  Panics if there are not enough bits for numbers
+/// distinct numbers
+pub fn generate(rng: &mut Rng, numbers: usize, bits: usize) -> String {
+    assert!(
+        bits < usize::BITS as usize && numbers <= 1 << bits,
+        "{} bits cannot hold {} distinct numbers",
+        bits,
+        numbers
+    );
+
+    loop {
+        let mut seen = HashSet::with_capacity(numbers);
+        while seen.len() < numbers {
+            seen.insert(rng.range(0..1 << bits));
+        }
+        let report: Vec<Vec<bool>> = seen
+            .into_iter()
+            .map(|number| (0..bits).rev().map(|bit| number >> bit & 1 == 1).collect())
+            .collect();
+
+        if life_support_rating(&report).is_ok() {
+            return report
+                .iter()
+                .map(|digits| {
+                    let mut line: String = digits
+                        .iter()
+                        .map(|one| if *one { '1' } else { '0' })
+                        .collect();
+                    line.push('\n');
+                    line
+                })
+                .collect();
+        }
+    }
+}
+
+/// A scale of 1 is about the size of the real report.  Longer reports get more digits so that the
+/// numbers stay sparse
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    let numbers = 1000 * scale;
+    let bits = (numbers * 4).next_power_of_two().trailing_zeros().max(12) as usize;
+    generate(rng, numbers, bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{bool_vec_to_usize, parse_str, power_consumption};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(3);
+        let report = parse_str(&generate(&mut rng, 400, 10)).unwrap();
+        assert_eq!(report.len(), 400);
+
+        // The most common digit of each column, counted the slow way
+        let gamma: Vec<bool> = (0..10)
+            .map(|column| report.iter().filter(|digits| digits[column]).count() * 2 > report.len())
+            .collect();
+        let gamma = bool_vec_to_usize(&gamma);
+        let epsilon = (1 << 10) - 1 - gamma;
+        assert_eq!(power_consumption(&report).unwrap(), gamma * epsilon);
+    }
+}
@@ -1,8 +1,32 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+pub struct DayThree;
+
+impl Solution for DayThree {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<bool>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        power_consumption(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        life_support_rating(input)
+    }
+}
+
 enum RatingSearch {
     Oxygen,
     CO2,
@@ -40,24 +64,56 @@ fn part_two_step(
         })
         .collect()
 }
-pub fn parse_input<P>(input_path: P) -> Result<Vec<Vec<bool>>>
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Vec<bool>>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
-    let mut return_vector = Vec::new();
-    for line in reader.lines().flatten() {
-        return_vector.push(
-            line.trim()
-                .chars()
-                .map(|x| match x {
-                    '0' => false,
-                    '1' => true,
-                    _ => panic!("invalid input.  Expected 0 or 1 got {:?}", x),
-                })
-                .collect::<Vec<bool>>(),
-        );
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Vec<bool>>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Vec<bool>>> {
+    parse_lines(input.lines())
+}
+
+/// Every line must be a binary number with the same number of digits as the first line
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Vec<bool>>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut return_vector: Vec<Vec<bool>> = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim();
+        let digits = line
+            .chars()
+            .enumerate()
+            .map(|(column_index, x)| match x {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(ParseError::invalid(line_index, column_index, x, "0 or 1")),
+            })
+            .collect::<ParseResult<Vec<bool>>>()?;
+
+        if let Some(first) = return_vector.first() {
+            if first.len() != digits.len() {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    format!("{} binary digits", first.len()),
+                ));
+            }
+        }
+        return_vector.push(digits);
     }
 
     Ok(return_vector)
@@ -67,12 +123,19 @@ pub fn part_two<P>(input_path: P) -> Result<usize>
 where
     P: AsRef<Path>,
 {
-    let remaining_lines = parse_input(input_path)?;
+    life_support_rating(&parse_input(input_path)?)
+}
+
+/// Return the oxygen generator rating * the CO2 scrubber rating
+fn life_support_rating(remaining_lines: &[Vec<bool>]) -> Result<usize> {
+    if remaining_lines.is_empty() {
+        return Err(anyhow!("Input must not be empty"));
+    }
 
     // This should be removed I'm just lazy atm
     let counts = vec![0; remaining_lines[0].len()];
 
-    let mut remaining_o2 = remaining_lines.clone();
+    let mut remaining_o2 = remaining_lines.to_vec();
     for index in 0..counts.len() {
         remaining_o2 = part_two_step(remaining_o2, index, RatingSearch::Oxygen);
         if remaining_o2.len() <= 1 {
@@ -80,7 +143,7 @@ where
         }
     }
 
-    let mut remaining_co2 = remaining_lines;
+    let mut remaining_co2 = remaining_lines.to_vec();
     for index in 0..counts.len() {
         remaining_co2 = part_two_step(remaining_co2, index, RatingSearch::CO2);
         if remaining_co2.len() <= 1 {
@@ -94,17 +157,20 @@ where
         return Err(anyhow!("No numbers remaining for co2 or o2"));
     }
 
-    Ok(bool_vec_to_usize(remaining_co2.get(0).unwrap())
-        * bool_vec_to_usize(remaining_o2.get(0).unwrap()))
+    Ok(bool_vec_to_usize(remaining_co2.first().unwrap())
+        * bool_vec_to_usize(remaining_o2.first().unwrap()))
 }
 
 pub fn part_one<P>(input_path: P) -> Result<usize>
 where
     P: AsRef<Path>,
 {
-    let parsed_input = parse_input(input_path)?;
+    power_consumption(&parse_input(input_path)?)
+}
 
-    let gamma_rate = part_one_gamma_rate(parsed_input)?;
+/// Return the gamma rate * the epsilon rate
+fn power_consumption(parsed_input: &[Vec<bool>]) -> Result<usize> {
+    let gamma_rate = part_one_gamma_rate(parsed_input.to_vec())?;
     //let epsilon_rate = part_
     let epsilon_rate = part_one_epsilon_rate_from_gamma(&gamma_rate);
 
@@ -148,13 +214,6 @@ fn part_one_gamma_rate(input: Vec<Vec<bool>>) -> Result<Vec<bool>> {
     Ok(return_vec)
 }
 
-fn part_one_epsilon_rate_from_input(input: Vec<Vec<bool>>) -> Result<Vec<bool>> {
-    Ok(part_one_gamma_rate(input)?
-        .into_iter()
-        .map(|x| !x)
-        .collect())
-}
-
 fn part_one_epsilon_rate_from_gamma(gamma_rate: &[bool]) -> Vec<bool> {
     gamma_rate.iter().fold(Vec::new(), |mut acc, digit| {
         acc.push(!digit);
@@ -165,8 +224,8 @@ fn part_one_epsilon_rate_from_gamma(gamma_rate: &[bool]) -> Vec<bool> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        bool_vec_to_usize, part_one, part_one_epsilon_rate_from_gamma, part_one_gamma_rate,
-        part_two, part_two_step, RatingSearch,
+        bool_vec_to_usize, parse_lines, part_one, part_one_epsilon_rate_from_gamma,
+        part_one_gamma_rate, part_two, part_two_step, RatingSearch,
     };
 
     #[test]
@@ -248,32 +307,19 @@ mod tests {
         assert_eq!(result, 198);
     }
 
-    #[test]
-    fn test_part_one_my_input() {
-        let result = part_one("src/input.txt").unwrap();
-
-        assert_eq!(result, 741950);
-    }
-
     #[test]
     fn test_part_two_example() {
         let result = part_two("src/example_input.txt").unwrap();
         assert_eq!(result, 230);
     }
 
-    #[test]
-    fn part_two_my_input() {
-        let result = part_two("src/input.txt").unwrap();
-        assert_eq!(result, 230);
-    }
-
     /// if there is a tie in the count between 0 and 1's for co2 0 should be chosen.
     #[test]
     fn test_part_two_co2_rounding() {
         let input = vec![vec![false], vec![true]];
         let result = part_two_step(input, 0, RatingSearch::Oxygen);
         assert_eq!(result.len(), 1);
-        assert!(*result.get(0).unwrap().get(0).unwrap());
+        assert!(*result.first().unwrap().first().unwrap());
     }
 
     /// if there is a tie in the count between 0 and 1's for o2 1 should be chosen.
@@ -282,6 +328,18 @@ mod tests {
         let input = vec![vec![false], vec![true]];
         let result = part_two_step(input, 0, RatingSearch::Oxygen);
         assert_eq!(result.len(), 1);
-        assert!(*result.get(0).unwrap().get(0).unwrap());
+        assert!(*result.first().unwrap().first().unwrap());
+    }
+
+    #[test]
+    fn test_parse_lines_invalid() {
+        let error = parse_lines(["00100", "11120"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected 0 or 1, found \"2\""
+        );
+
+        let error = parse_lines(["00100", "1110"]).unwrap_err();
+        assert_eq!(error.line(), Some(2));
     }
 }
@@ -0,0 +1,151 @@
+//! Random cave systems.
+use aoc_common::Rng;
+use std::collections::HashSet;
+
+/// A cave system of small_caves small caves, big_caves big caves and about connections
+/// passages, one `from-to` pair per line.
+///
+/// Every cave can be reached from start and two big caves are never joined, since the paths
+/// through them would never end.  The number of paths grows very quickly with the number of
+/// passages.  Panics if there are more caves than two letter names
+pub fn generate(rng: &mut Rng, small_caves: usize, big_caves: usize, connections: usize) -> String {
+    let small = names(rng, small_caves, 'a');
+    let big = names(rng, big_caves, 'A');
+
+    // The start goes first so every cave after it can be tied to one before it.  The start is
+    // never big so there is always one to pick
+    let mut caves: Vec<String> = small.into_iter().chain(big).collect();
+    caves.push("end".to_string());
+    rng.shuffle(&mut caves);
+    caves.insert(0, "start".to_string());
+
+    let is_big = |cave: &str| cave.chars().all(|letter| letter.is_ascii_uppercase());
+    let mut passages: Vec<(usize, usize)> = Vec::new();
+    let mut joined = HashSet::new();
+    let mut join = |passages: &mut Vec<(usize, usize)>, from: usize, to: usize| {
+        if from != to && joined.insert((from.min(to), from.max(to))) {
+            passages.push((from, to));
+        }
+    };
+
+    for cave in 1..caves.len() {
+        let candidates: Vec<usize> = (0..cave)
+            .filter(|earlier| !(is_big(&caves[cave]) && is_big(&caves[*earlier])))
+            .collect();
+        join(&mut passages, *rng.choose(&candidates), cave);
+    }
+
+    // Trying a few more times than needed makes up for pairs that cannot be joined
+    for _ in 0..connections.saturating_sub(passages.len()) * 4 {
+        if passages.len() >= connections {
+            break;
+        }
+        let (from, to) = (rng.range(0..caves.len()), rng.range(0..caves.len()));
+        if !(is_big(&caves[from]) && is_big(&caves[to])) {
+            join(&mut passages, from, to);
+        }
+    }
+
+    rng.shuffle(&mut passages);
+    passages
+        .into_iter()
+        .map(|(from, to)| {
+            if rng.chance(0.5) {
+                format!("{}-{}\n", caves[from], caves[to])
+            } else {
+                format!("{}-{}\n", caves[to], caves[from])
+            }
+        })
+        .collect()
+}
+
+/// A scale of 1 is about the size of the real cave system.  Because the number of paths explodes
+/// the cave only gains a small cave and two passages for every ten steps of scale
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 6 + scale / 10, 3, 22 + scale / 5)
+}
+
+/// count distinct two letter names starting from first_letter
+fn names(rng: &mut Rng, count: usize, first_letter: char) -> Vec<String> {
+    let letters: Vec<char> = (0..26)
+        .filter_map(|offset| char::from_u32(first_letter as u32 + offset))
+        .collect();
+    assert!(
+        count <= letters.len() * letters.len(),
+        "not enough names for {} caves",
+        count
+    );
+
+    let mut names = HashSet::with_capacity(count);
+    while names.len() < count {
+        names.insert(format!("{}{}", rng.choose(&letters), rng.choose(&letters)));
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort_unstable();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+    use std::collections::HashMap;
+
+    /// Walk every path keeping the whole path around.  A small cave may appear twice in one path
+    /// if twice_allowed and no other small cave has
+    fn count_paths<'a>(
+        passages: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        twice_allowed: bool,
+    ) -> usize {
+        let here = *path.last().unwrap();
+        if here == "end" {
+            return 1;
+        }
+
+        let mut paths = 0;
+        for next in &passages[here] {
+            let small = next.chars().all(|letter| letter.is_ascii_lowercase());
+            let visits = path.iter().filter(|cave| *cave == next).count();
+            let again = if small && visits > 0 {
+                if !twice_allowed || *next == "start" {
+                    continue;
+                }
+                false
+            } else {
+                twice_allowed
+            };
+
+            path.push(next);
+            paths += count_paths(passages, path, again);
+            path.pop();
+        }
+        paths
+    }
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(12);
+        for _ in 0..5 {
+            let input = generate(&mut rng, 5, 2, 12);
+            let lines = parse_str(&input).unwrap();
+
+            let mut passages: HashMap<&str, Vec<&str>> = HashMap::new();
+            for line in input.lines() {
+                let (from, to) = line.split_once('-').unwrap();
+                passages.entry(from).or_default().push(to);
+                passages.entry(to).or_default().push(from);
+            }
+
+            assert_eq!(
+                part_one(lines.clone()).unwrap(),
+                count_paths(&passages, &mut vec!["start"], false)
+            );
+            assert_eq!(
+                part_two(lines).unwrap(),
+                count_paths(&passages, &mut vec!["start"], true)
+            );
+        }
+    }
+}
@@ -1,10 +1,80 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
 };
 
-fn part_one(input_string: Vec<String>) -> Result<usize> {
+pub struct DayTwelve;
+
+impl Solution for DayTwelve {
+    const DAY: u8 = 12;
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input.clone())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input.clone())
+    }
+}
+
+/// Collect the cave connections located at input_path.  One `from-to` pair per line
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<String>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<String>> {
+    parse_lines(input.lines())
+}
+
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<String>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_index, line)| (line_index, line.as_ref().trim().to_string()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| match line.split_once('-') {
+            Some((from, to)) if !from.is_empty() && !to.is_empty() && !to.contains('-') => Ok(line),
+            _ => Err(ParseError::invalid(
+                line_index,
+                0,
+                line,
+                "two caves joined by a single -",
+            )),
+        })
+        .collect()
+}
+
+pub fn part_one(input_string: Vec<String>) -> Result<usize> {
     let initial_hash_set = RefCell::new(HashSet::new());
     initial_hash_set.borrow_mut().insert("start".to_string());
     depth_first_search_all_paths_part_one(
@@ -14,7 +84,7 @@ fn part_one(input_string: Vec<String>) -> Result<usize> {
     )
 }
 
-fn part_two(input_string: Vec<String>) -> Result<usize> {
+pub fn part_two(input_string: Vec<String>) -> Result<usize> {
     let initial_hash_set = RefCell::new(HashSet::new());
     initial_hash_set.borrow_mut().insert("start".to_string());
     depth_first_search_all_paths_part_two(
@@ -176,7 +246,7 @@ fn depth_first_search_all_paths_part_one(
 mod tests {
     use std::collections::HashSet;
 
-    use crate::{part_one, part_two};
+    use crate::{parse_str, part_one, part_two};
 
     #[test]
     fn it_works() {
@@ -210,79 +280,49 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end"
-            .split("\n")
-            .map(|a| a.to_string())
-            .collect::<Vec<String>>();
+        let input = parse_str("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end").unwrap();
         let result = part_one(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 10);
 
-        let input =
-            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc"
-                .to_string()
-                .split("\n")
-                .map(|a| a.to_string())
-                .collect::<Vec<String>>();
+        let input = parse_str(
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        )
+        .unwrap();
         let result = part_one(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 19);
 
-        let input = "fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW".to_string().split("\n").map(|a|a.to_string()).collect::<Vec<String>>();
+        let input = parse_str("fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW").unwrap();
         let result = part_one(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 226);
     }
 
-    #[test]
-    fn test_part_one_my_input() {
-        let input = "CV-mk\ngm-IK\nsk-gm\nca-sk\nsx-mk\ngm-start\nsx-ca\nkt-sk\nca-VS\nkt-ml\nkt-ca\nmk-IK\nend-sx\nend-sk\ngy-sx\nend-ca\nca-ml\ngm-CV\nsx-kt\nstart-CV\nIK-start\nCV-kt\nml-mk\nml-CV\nml-gm\nml-IK".to_string().split("\n").map(|a|a.to_string()).collect::<Vec<String>>();
-
-        let result = part_one(input);
-
-        assert!(result.is_ok(), "Result {:?} failed", result);
-        assert_eq!(result.unwrap(), 4186);
-    }
-
     #[test]
     fn test_part_two() {
-        let input = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end"
-            .split("\n")
-            .map(|a| a.to_string())
-            .collect::<Vec<String>>();
+        let input = parse_str("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end").unwrap();
         let result = part_two(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 36);
 
-        let input =
-            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc"
-                .to_string()
-                .split("\n")
-                .map(|a| a.to_string())
-                .collect::<Vec<String>>();
+        let input = parse_str(
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        )
+        .unwrap();
         let result = part_two(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 103);
 
-        let input = "fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW".to_string().split("\n").map(|a|a.to_string()).collect::<Vec<String>>();
+        let input = parse_str("fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW").unwrap();
         let result = part_two(input);
 
         assert!(result.is_ok(), "Result {:?} failed", result);
         assert_eq!(result.unwrap(), 3509);
     }
-
-    #[test]
-    fn part_two_my_input() {
-        let input = "CV-mk\ngm-IK\nsk-gm\nca-sk\nsx-mk\ngm-start\nsx-ca\nkt-sk\nca-VS\nkt-ml\nkt-ca\nmk-IK\nend-sx\nend-sk\ngy-sx\nend-ca\nca-ml\ngm-CV\nsx-kt\nstart-CV\nIK-start\nCV-kt\nml-mk\nml-CV\nml-gm\nml-IK".to_string().split("\n").map(|a|a.to_string()).collect::<Vec<String>>();
-
-        let result = part_two(input);
-
-        assert!(result.is_ok(), "Result {:?} failed", result);
-        assert_eq!(result.unwrap(), 92111);
-    }
 }
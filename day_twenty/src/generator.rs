@@ -0,0 +1,54 @@
+//! Random trench maps.
+use crate::Algorithm;
+use aoc_common::Rng;
+
+/// A random algorithm and a size by size image.  Like the real algorithms, about half of
+/// these light the dark background, but never keep a lit one lit, so the count stays finite
+/// after an even number of passes
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+
+    let mut algorithm: Vec<char> = (0..Algorithm::LEN).map(|_| pixel(rng)).collect();
+    if algorithm[0] == '#' {
+        algorithm[Algorithm::LEN - 1] = '.';
+    }
+
+    let mut input: String = algorithm.into_iter().collect();
+    input.push_str("\n\n");
+    for _ in 0..size {
+        input.extend((0..size).map(|_| pixel(rng)));
+        input.push('\n');
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real image
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 100 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{lit_after, parse_str, Algorithm};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(20);
+        for _ in 0..10 {
+            let trench_map = parse_str(&generate(&mut rng, 12)).unwrap();
+            let pixels = trench_map.image.pixels();
+            assert_eq!((pixels.width(), pixels.height()), (12, 12));
+            let algorithm = &trench_map.algorithm;
+            assert!(!(algorithm.lights(0) && algorithm.lights(Algorithm::LEN - 1)));
+
+            // However the background flips, it is dark again after every second pass
+            assert!(lit_after(&trench_map, 2).is_ok());
+            assert!(lit_after(&trench_map, 4).is_ok());
+            let once = trench_map.image.enhance(algorithm);
+            assert_eq!(once.background(), algorithm.lights(0));
+        }
+    }
+}
@@ -0,0 +1,314 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_grid::Grid;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTwenty;
+
+impl Solution for DayTwenty {
+    const DAY: u8 = 20;
+    type Input = TrenchMap;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        lit_after(input, 2)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        lit_after(input, 50)
+    }
+}
+
+/// The puzzle input: how to enhance and what to start from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrenchMap {
+    pub algorithm: Algorithm,
+    pub image: Image,
+}
+
+/// Whether the output pixel is lit for each of the 512 ways a 3x3 square can be lit, read
+/// left to right and top to bottom as a binary number with lit as 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Algorithm([bool; Algorithm::LEN]);
+
+impl Algorithm {
+    pub const LEN: usize = 512;
+
+    pub fn new(lit: [bool; Algorithm::LEN]) -> Self {
+        Algorithm(lit)
+    }
+
+    pub fn lights(&self, index: usize) -> bool {
+        self.0[index]
+    }
+}
+
+/// A finite patch of lit and dark pixels in the middle of an infinite background that is all
+/// one or the other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+impl Image {
+    pub fn new(pixels: Grid<bool>, background: bool) -> Self {
+        Image { pixels, background }
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    /// Whether every pixel outside of [`Self::pixels`] is lit
+    pub fn background(&self) -> bool {
+        self.background
+    }
+
+    /// The number of lit pixels, or None if the background is lit so there are infinitely many
+    pub fn lit_count(&self) -> Option<usize> {
+        if self.background {
+            None
+        } else {
+            Some(self.pixels.iter().filter(|lit| **lit).count())
+        }
+    }
+
+    /// Rows and columns past the edges are the background
+    fn pixel(&self, row: isize, column: isize) -> bool {
+        if row < 0 || column < 0 {
+            return self.background;
+        }
+        *self
+            .pixels
+            .get((row as usize, column as usize))
+            .unwrap_or(&self.background)
+    }
+
+    /// Apply algorithm to every pixel at once.  The image grows by one pixel on every side,
+    /// since those are the only pixels outside it that can end up different from the background.
+    /// The background itself is all dark or all lit squares, so it becomes the first or last
+    /// entry of algorithm, which flips it every pass when the first entry is lit
+    pub fn enhance(&self, algorithm: &Algorithm) -> Image {
+        let mut pixels = Grid::new(self.pixels.width() + 2, self.pixels.height() + 2, false);
+        for ((row, column), pixel) in pixels.indexed_iter_mut() {
+            // The old image starts one pixel in from the new one
+            let (row, column) = (row as isize - 1, column as isize - 1);
+            let index = (-1..=1)
+                .flat_map(|row_offset| {
+                    (-1..=1).map(move |column_offset| (row_offset, column_offset))
+                })
+                .fold(0, |index, (row_offset, column_offset)| {
+                    index << 1 | usize::from(self.pixel(row + row_offset, column + column_offset))
+                });
+            *pixel = algorithm.lights(index);
+        }
+
+        let background = if self.background {
+            algorithm.lights(Algorithm::LEN - 1)
+        } else {
+            algorithm.lights(0)
+        };
+
+        Image { pixels, background }
+    }
+
+    pub fn enhance_times(&self, algorithm: &Algorithm, passes: usize) -> Image {
+        (0..passes).fold(self.clone(), |image, _| image.enhance(algorithm))
+    }
+}
+
+/// The finite part as `#` for lit and `.` for dark, one row per line
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.pixels.rows() {
+            let row: String = row.iter().map(|lit| if *lit { '#' } else { '.' }).collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The lit pixels after passes enhancements.  Fails if the background is lit by then
+pub fn lit_after(trench_map: &TrenchMap, passes: usize) -> Result<usize> {
+    trench_map
+        .image
+        .enhance_times(&trench_map.algorithm, passes)
+        .lit_count()
+        .ok_or_else(|| anyhow!("Infinitely many pixels are lit after {} passes", passes))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<TrenchMap>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<TrenchMap>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<TrenchMap> {
+    parse_lines(input.lines())
+}
+
+/// The algorithm on the first line, then the image one row per line.  Both are `#` for lit
+/// and `.` for dark.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<TrenchMap>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, line)| !line.as_ref().trim().is_empty());
+
+    let (line_index, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::unexpected_end(0, "an image enhancement algorithm"))?;
+    let line = line.as_ref().trim();
+    let lit = parse_pixels(line_index, line)?;
+    let algorithm: [bool; Algorithm::LEN] = lit.try_into().map_err(|_| {
+        ParseError::invalid(
+            line_index,
+            0,
+            line,
+            format!("an algorithm of {} pixels", Algorithm::LEN),
+        )
+    })?;
+
+    let mut width = None;
+    let mut pixels = Vec::new();
+    for (line_index, line) in lines {
+        let line = line.as_ref().trim();
+        let row = parse_pixels(line_index, line)?;
+        match width {
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    format!("a row of {} pixels", width),
+                ))
+            }
+            Some(_) => (),
+        }
+        pixels.extend(row);
+    }
+
+    Ok(TrenchMap {
+        algorithm: Algorithm(algorithm),
+        image: Image {
+            pixels: Grid::from_vec(width.unwrap_or(0), pixels)
+                .expect("every row has the same width"),
+            background: false,
+        },
+    })
+}
+
+fn parse_pixels(line_index: usize, line: &str) -> ParseResult<Vec<bool>> {
+    line.chars()
+        .enumerate()
+        .map(|(column_index, pixel)| match pixel {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::invalid(
+                line_index,
+                column_index,
+                pixel,
+                "# or .",
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lit_after, parse_input, parse_str, Algorithm, Image};
+    use aoc_grid::Grid;
+
+    #[test]
+    fn test_parse_input() {
+        let trench_map = parse_input("src/example_input.txt").unwrap();
+        assert!(!trench_map.algorithm.lights(0));
+        assert!(trench_map.algorithm.lights(34));
+        assert_eq!(
+            trench_map.image.to_string(),
+            "#..#.\n#....\n##..#\n..#..\n..###\n"
+        );
+        assert!(!trench_map.image.background());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let algorithm = "#".repeat(Algorithm::LEN);
+        let error = parse_str(&format!("{}\n\n#.\n#x\n", algorithm)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 2: expected # or ., found \"x\""
+        );
+        assert!(parse_str(&format!("{}\n\n#.\n#\n", algorithm)).is_err());
+        assert!(parse_str("#.#\n\n#.\n").is_err());
+        assert!(parse_str("").is_err());
+    }
+
+    #[test]
+    fn test_enhance() {
+        let trench_map = parse_input("src/example_input.txt").unwrap();
+        let image = trench_map.image.enhance(&trench_map.algorithm);
+        assert_eq!(
+            image.to_string(),
+            ".##.##.\n#..#.#.\n##.#..#\n####..#\n.#..##.\n..##..#\n...#.#.\n"
+        );
+        assert_eq!(image.lit_count(), Some(24));
+    }
+
+    #[test]
+    fn test_flipping_background() {
+        // Only a square with nothing lit in it lights up, so the dark background does
+        let mut lit = [false; Algorithm::LEN];
+        lit[0] = true;
+        let algorithm = Algorithm::new(lit);
+        let image = Image::new(Grid::new(1, 1, true), false);
+
+        let once = image.enhance(&algorithm);
+        assert!(once.background());
+        assert_eq!(once.lit_count(), None);
+        assert_eq!(once.to_string(), "...\n...\n...\n");
+
+        let twice = once.enhance(&algorithm);
+        assert!(!twice.background());
+        assert_eq!(twice.to_string(), ".....\n.....\n..#..\n.....\n.....\n");
+        assert_eq!(twice.lit_count(), Some(1));
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let trench_map = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(lit_after(&trench_map, 2).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let trench_map = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(lit_after(&trench_map, 50).unwrap(), 3351);
+    }
+}
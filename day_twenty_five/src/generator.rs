@@ -0,0 +1,50 @@
+//! Random sea floors.
+use crate::{first_still_step, parse_str};
+use aoc_common::Rng;
+
+/// A width by height map where about two thirds of the cells hold a sea cucumber of either
+/// herd.  Some random maps keep a herd circling forever, so those are thrown away to leave one
+/// that comes to a stop like the real ones
+pub fn generate(rng: &mut Rng, width: usize, height: usize) -> String {
+    loop {
+        let mut input = String::new();
+        for _ in 0..height {
+            input.extend((0..width).map(|_| *rng.choose(&['>', 'v', '.'])));
+            input.push('\n');
+        }
+
+        let sea_floor = parse_str(&input).expect("generated maps are valid");
+        if first_still_step(&sea_floor).is_ok() {
+            return input;
+        }
+    }
+}
+
+/// A scale of 1 is about the size of the real map.  Only the width grows
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 139 * scale, 137)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{first_still_step, parse_str};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(25);
+        for _ in 0..5 {
+            let sea_floor = parse_str(&generate(&mut rng, 30, 20)).unwrap();
+            assert_eq!(sea_floor.cells().width(), 30);
+            assert_eq!(sea_floor.cells().height(), 20);
+
+            let steps = first_still_step(&sea_floor).unwrap();
+            let mut still = sea_floor.clone();
+            for _ in 1..steps {
+                assert!(still.step() > 0);
+            }
+            assert_eq!(still.step(), 0);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use aoc_grid::Grid;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTwentyFive;
+
+impl Solution for DayTwentyFive {
+    const DAY: u8 = 25;
+    type Input = SeaFloor;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        first_still_step(input)
+    }
+
+    /// The last day only has one puzzle, and the second star comes from finishing every other
+    fn part_two(_input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(String::from("Merry Christmas"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    /// A sea cucumber of the east facing herd
+    East,
+    /// A sea cucumber of the south facing herd
+    South,
+}
+
+impl Cell {
+    fn symbol(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        }
+    }
+}
+
+/// The sea cucumbers, on a map that wraps around on both axes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeaFloor {
+    cells: Grid<Cell>,
+}
+
+impl SeaFloor {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        SeaFloor { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Move every sea cucumber of herd that faces an empty cell, all at once, so none of them
+    /// can move into a cell another is leaving.  Returns how many moved
+    fn move_herd(&mut self, herd: Cell) -> usize {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let ahead = |(row, column): (usize, usize)| match herd {
+            Cell::South => ((row + 1) % height, column),
+            _ => (row, (column + 1) % width),
+        };
+
+        let moving: Vec<_> = self
+            .cells
+            .indexed_iter()
+            .filter(|(position, cell)| {
+                **cell == herd && self.cells[ahead(*position)] == Cell::Empty
+            })
+            .map(|(position, _)| position)
+            .collect();
+        for position in &moving {
+            self.cells[*position] = Cell::Empty;
+            self.cells[ahead(*position)] = herd;
+        }
+
+        moving.len()
+    }
+
+    /// The east facing herd moves and then the south facing one does.  Returns how many sea
+    /// cucumbers moved in total
+    pub fn step(&mut self) -> usize {
+        self.move_herd(Cell::East) + self.move_herd(Cell::South)
+    }
+}
+
+/// The map as `>` and `v` for each herd and `.` for empty, one row per line
+impl fmt::Display for SeaFloor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            let row: String = row.iter().map(Cell::symbol).collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of the first step in which no sea cucumber moves.  Fails if they keep moving
+/// around the same loop forever, which is caught by comparing against a copy saved at every
+/// power of two steps
+pub fn first_still_step(sea_floor: &SeaFloor) -> Result<usize> {
+    let mut current = sea_floor.clone();
+    let mut saved = sea_floor.clone();
+    let mut saved_step = 0;
+    let mut step = 0;
+    loop {
+        step += 1;
+        if current.step() == 0 {
+            return Ok(step);
+        }
+        if current == saved {
+            return Err(anyhow!(
+                "The sea cucumbers never stop, and repeat every {} steps",
+                step - saved_step
+            ));
+        }
+        if step.is_power_of_two() {
+            saved = current.clone();
+            saved_step = step;
+        }
+    }
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<SeaFloor>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<SeaFloor>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<SeaFloor> {
+    parse_lines(input.lines())
+}
+
+/// One row of the map per line, `>` and `v` for each herd and `.` for empty.  Blank lines are
+/// skipped
+fn parse_lines<I>(lines: I) -> ParseResult<SeaFloor>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut width = None;
+    let mut cells = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let row_width = line.chars().count();
+        match width {
+            None => width = Some(row_width),
+            Some(width) if width != row_width => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    line,
+                    format!("a row of {} cells", width),
+                ))
+            }
+            Some(_) => (),
+        }
+
+        for (column_index, cell) in line.chars().enumerate() {
+            cells.push(match cell {
+                '.' => Cell::Empty,
+                '>' => Cell::East,
+                'v' => Cell::South,
+                _ => {
+                    return Err(ParseError::invalid(
+                        line_index,
+                        column_index,
+                        cell,
+                        ">, v or .",
+                    ))
+                }
+            });
+        }
+    }
+
+    Ok(SeaFloor {
+        cells: Grid::from_vec(width.unwrap_or(0), cells).expect("every row has the same width"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{first_still_step, parse_input, parse_str, Cell};
+
+    #[test]
+    fn test_parse_input() {
+        let sea_floor = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(sea_floor.cells().width(), 10);
+        assert_eq!(sea_floor.cells().height(), 9);
+        assert_eq!(sea_floor.cells()[(0, 0)], Cell::South);
+        assert_eq!(sea_floor.cells()[(0, 4)], Cell::East);
+        assert_eq!(
+            sea_floor.to_string(),
+            std::fs::read_to_string("src/example_input.txt").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("v..>\n.<..\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected >, v or ., found \"<\""
+        );
+        assert!(parse_str("v..>\n.>.\n").is_err());
+    }
+
+    #[test]
+    fn test_step() {
+        let mut sea_floor = parse_str("...>>>>>...\n").unwrap();
+        assert_eq!(sea_floor.step(), 1);
+        assert_eq!(sea_floor.to_string(), "...>>>>.>..\n");
+        assert_eq!(sea_floor.step(), 2);
+        assert_eq!(sea_floor.to_string(), "...>>>.>.>.\n");
+    }
+
+    #[test]
+    fn test_step_wraps() {
+        // The east facing herd moves first, so the south facing one finds the way blocked
+        let mut sea_floor = parse_str("..........\n.>v....v..\n.......>..\n..........\n").unwrap();
+        sea_floor.step();
+        assert_eq!(
+            sea_floor.to_string(),
+            "..........\n.>........\n..v....v>.\n..........\n"
+        );
+
+        // Both herds wrap around, and the south facing one can take a place left this step
+        let mut sea_floor = parse_str("...>\nv...\n>...\n").unwrap();
+        sea_floor.step();
+        assert_eq!(sea_floor.to_string(), ">...\n....\nv>..\n");
+    }
+
+    #[test]
+    fn test_step_example() {
+        let mut sea_floor = parse_input("src/example_input.txt").unwrap();
+        sea_floor.step();
+        assert_eq!(
+            sea_floor.to_string(),
+            "....>.>v.>\n\
+             v.v>.>v.v.\n\
+             >v>>..>v..\n\
+             >>v>v>.>.v\n\
+             .>v.v...v.\n\
+             v>>.>vvv..\n\
+             ..v...>>..\n\
+             vv...>>vv.\n\
+             >.v.v..v.v\n"
+        );
+    }
+
+    #[test]
+    fn test_never_still() {
+        let sea_floor = parse_str(">.>.\n").unwrap();
+        assert!(first_still_step(&sea_floor).is_err());
+
+        let jammed = parse_str(">>>>\nvvvv\n").unwrap();
+        assert_eq!(first_still_step(&jammed).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let sea_floor = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(first_still_step(&sea_floor).unwrap(), 58);
+    }
+}
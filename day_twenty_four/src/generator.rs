@@ -0,0 +1,67 @@
+//! Random MONAD programs.
+use crate::{Block, DIGITS};
+use aoc_common::Rng;
+
+/// A MONAD that accepts some model numbers, written out one instruction per line
+pub fn generate(rng: &mut Rng) -> String {
+    random_blocks(rng)
+        .iter()
+        .flat_map(|block| block.instructions())
+        .map(|instruction| format!("{}\n", instruction))
+        .collect()
+}
+
+/// There is nothing to scale, since MONAD only ever checks fourteen digits
+pub fn generate_scaled(rng: &mut Rng, _scale: usize) -> String {
+    generate(rng)
+}
+
+/// Half the blocks push and half pop, in an order that never pops more than was pushed.  Each
+/// pop matches its push when the two digits differ by up to 8
+pub fn random_blocks(rng: &mut Rng) -> Vec<Block> {
+    let mut pushes_left = DIGITS / 2;
+    let mut pushed = Vec::new();
+    let mut blocks = Vec::new();
+    for _ in 0..DIGITS {
+        let offset = rng.range(0..17) as i64;
+        if pushes_left > 0 && (pushed.is_empty() || rng.chance(0.5)) {
+            pushes_left -= 1;
+            pushed.push(offset);
+            blocks.push(Block {
+                divisor: 1,
+                check: 10 + rng.range(0..7) as i64,
+                offset,
+            });
+        } else {
+            let pushed_offset = pushed.pop().expect("as many pops as pushes");
+            let difference = rng.range(0..17) as i64 - 8;
+            blocks.push(Block {
+                divisor: 26,
+                check: difference - pushed_offset,
+                offset,
+            });
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{find_model_number, parse_str, Preference};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(24);
+        for _ in 0..10 {
+            let program = parse_str(&generate(&mut rng)).unwrap();
+            let largest = find_model_number(&program, Preference::Largest).unwrap();
+            let smallest = find_model_number(&program, Preference::Smallest).unwrap();
+            assert!(smallest <= largest);
+            assert_eq!(largest.to_string().len(), 14);
+            assert_eq!(smallest.to_string().len(), 14);
+        }
+    }
+}
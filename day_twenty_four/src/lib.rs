@@ -0,0 +1,678 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTwentyFour;
+
+impl Solution for DayTwentyFour {
+    const DAY: u8 = 24;
+    type Input = Vec<Instruction>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        find_model_number(input, Preference::Largest)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        find_model_number(input, Preference::Smallest)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    fn name(&self) -> char {
+        match self {
+            Register::W => 'w',
+            Register::X => 'x',
+            Register::Y => 'y',
+            Register::Z => 'z',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register.name()),
+            Operand::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+/// Everything but inp stores the result of combining both arguments in the first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+/// The line of the program it was parsed from
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, register, operand) = match self {
+            Instruction::Inp(register) => return write!(f, "inp {}", register.name()),
+            Instruction::Add(register, operand) => ("add", register, operand),
+            Instruction::Mul(register, operand) => ("mul", register, operand),
+            Instruction::Div(register, operand) => ("div", register, operand),
+            Instruction::Mod(register, operand) => ("mod", register, operand),
+            Instruction::Eql(register, operand) => ("eql", register, operand),
+        };
+        write!(f, "{} {} {}", name, register.name(), operand)
+    }
+}
+
+/// The arithmetic logic unit, with its four registers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    /// Every register starts at 0
+    pub fn new() -> Self {
+        Alu::default()
+    }
+
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(number) => number,
+        }
+    }
+
+    /// inp reads the next value of input.  Fails on running out of input, dividing by 0, a
+    /// modulo of a negative number or by anything but a positive one, and overflow
+    pub fn execute<I>(&mut self, instruction: &Instruction, input: &mut I) -> Result<()>
+    where
+        I: Iterator<Item = i64>,
+    {
+        let (register, result) = match *instruction {
+            Instruction::Inp(register) => {
+                let value = input
+                    .next()
+                    .ok_or_else(|| anyhow!("Ran out of input for {}", instruction))?;
+                (register, Some(value))
+            }
+            Instruction::Add(register, operand) => (
+                register,
+                self.get(register).checked_add(self.value(operand)),
+            ),
+            Instruction::Mul(register, operand) => (
+                register,
+                self.get(register).checked_mul(self.value(operand)),
+            ),
+            Instruction::Div(register, operand) => {
+                let divisor = self.value(operand);
+                if divisor == 0 {
+                    return Err(anyhow!("Divided by 0 in {}", instruction));
+                }
+                (register, self.get(register).checked_div(divisor))
+            }
+            Instruction::Mod(register, operand) => {
+                let (value, modulus) = (self.get(register), self.value(operand));
+                if value < 0 || modulus <= 0 {
+                    return Err(anyhow!(
+                        "{} mod {} is not allowed in {}",
+                        value,
+                        modulus,
+                        instruction
+                    ));
+                }
+                (register, Some(value % modulus))
+            }
+            Instruction::Eql(register, operand) => (
+                register,
+                Some(i64::from(self.get(register) == self.value(operand))),
+            ),
+        };
+
+        self.registers[register.index()] =
+            result.ok_or_else(|| anyhow!("Overflow in {}", instruction))?;
+        Ok(())
+    }
+
+    /// Run every instruction of program from a fresh ALU.  Any input left over is ignored
+    pub fn run<I>(program: &[Instruction], input: I) -> Result<Alu>
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        let mut alu = Alu::new();
+        let mut input = input.into_iter();
+        for instruction in program {
+            alu.execute(instruction, &mut input)?;
+        }
+        Ok(alu)
+    }
+}
+
+/// The number of digits in a model number, which is also the number of blocks in MONAD
+pub const DIGITS: usize = 14;
+const BLOCK_LEN: usize = 18;
+
+/// The parameters of one of the blocks MONAD is made of.  Every block reads the next digit
+/// into w and runs the same instructions, which come down to
+///
+/// ```text
+/// if z % 26 + check == w { z /= divisor } else { z = z / divisor * 26 + w + offset }
+/// ```
+///
+/// Treating z as a stack of base 26 digits, a block with divisor 1 and a check too large to
+/// ever match pushes w + offset.  A block with divisor 26 pops the top of the stack, and only
+/// pushes in its place if w does not match it.  With as many of one as of the other, z only
+/// ends up 0 if every pop matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub divisor: i64,
+    pub check: i64,
+    pub offset: i64,
+}
+
+impl Block {
+    pub fn instructions(&self) -> Vec<Instruction> {
+        use Instruction::*;
+        use Operand::Number;
+        use Register::*;
+        let register = Operand::Register;
+
+        vec![
+            Inp(W),
+            Mul(X, Number(0)),
+            Add(X, register(Z)),
+            Mod(X, Number(26)),
+            Div(Z, Number(self.divisor)),
+            Add(X, Number(self.check)),
+            Eql(X, register(W)),
+            Eql(X, Number(0)),
+            Mul(Y, Number(0)),
+            Add(Y, Number(25)),
+            Mul(Y, register(X)),
+            Add(Y, Number(1)),
+            Mul(Z, register(Y)),
+            Mul(Y, Number(0)),
+            Add(Y, register(W)),
+            Add(Y, Number(self.offset)),
+            Mul(Y, register(X)),
+            Add(Z, register(Y)),
+        ]
+    }
+
+    /// Read the parameters of a block, and make sure it is otherwise exactly like every other
+    fn from_instructions(index: usize, instructions: &[Instruction]) -> Result<Block> {
+        let number = |line: usize| match instructions.get(line) {
+            Some(
+                Instruction::Div(Register::Z, Operand::Number(number))
+                | Instruction::Add(Register::X | Register::Y, Operand::Number(number)),
+            ) => Some(*number),
+            _ => None,
+        };
+        let block = match (number(4), number(5), number(15)) {
+            (Some(divisor), Some(check), Some(offset)) => Block {
+                divisor,
+                check,
+                offset,
+            },
+            _ => return Err(anyhow!("Block {} is not shaped like MONAD's", index)),
+        };
+        if block.instructions() != instructions {
+            return Err(anyhow!("Block {} is not shaped like MONAD's", index));
+        }
+        Ok(block)
+    }
+}
+
+/// Split MONAD into its blocks, one per digit
+pub fn blocks(program: &[Instruction]) -> Result<Vec<Block>> {
+    if program.len() != DIGITS * BLOCK_LEN {
+        return Err(anyhow!(
+            "A program of {} instructions is not MONAD, which has {}",
+            program.len(),
+            DIGITS * BLOCK_LEN
+        ));
+    }
+
+    program
+        .chunks(BLOCK_LEN)
+        .enumerate()
+        .map(|(index, instructions)| Block::from_instructions(index, instructions))
+        .collect()
+}
+
+/// Digit second of the model number must be digit first plus difference, counting from the
+/// most significant digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub first: usize,
+    pub second: usize,
+    pub difference: i64,
+}
+
+/// Pair each block that pops with the block that pushed what it pops.  Together these are
+/// everything a model number needs for MONAD to accept it
+pub fn constraints(blocks: &[Block]) -> Result<Vec<Constraint>> {
+    let mut pushed = Vec::new();
+    let mut constraints = Vec::new();
+    for (index, block) in blocks.iter().enumerate() {
+        // w + offset must be a single base 26 digit, and never 0 so that pushing it always
+        // leaves z above 0
+        if !(0..=16).contains(&block.offset) {
+            return Err(anyhow!(
+                "Block {} adds {}, which can take w past a base 26 digit",
+                index,
+                block.offset
+            ));
+        }
+
+        match block.divisor {
+            1 if block.check > 9 => pushed.push((index, block.offset)),
+            1 => return Err(anyhow!("Block {} can match w without popping", index)),
+            26 => {
+                let (first, offset) = pushed
+                    .pop()
+                    .ok_or_else(|| anyhow!("Block {} pops when nothing was pushed", index))?;
+                let difference = offset + block.check;
+                if difference.abs() > 8 {
+                    return Err(anyhow!(
+                        "No digits of blocks {} and {} differ by {}",
+                        first,
+                        index,
+                        difference
+                    ));
+                }
+                constraints.push(Constraint {
+                    first,
+                    second: index,
+                    difference,
+                });
+            }
+            divisor => {
+                return Err(anyhow!(
+                    "Block {} divides by {}, not 1 or 26",
+                    index,
+                    divisor
+                ))
+            }
+        }
+    }
+    if let Some((index, _)) = pushed.first() {
+        return Err(anyhow!("Nothing pops what block {} pushes", index));
+    }
+
+    Ok(constraints)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preference {
+    Largest,
+    Smallest,
+}
+
+/// The model number that meets constraints, as large or as small as possible.  Each digit is
+/// in exactly one constraint, so each pair can be picked on its own
+pub fn model_number(constraints: &[Constraint], preference: Preference) -> u64 {
+    let mut digits = [0; DIGITS];
+    for constraint in constraints {
+        let difference = constraint.difference;
+        let (first, second) = match preference {
+            Preference::Largest => (9.min(9 - difference), 9.min(9 + difference)),
+            Preference::Smallest => (1.max(1 - difference), 1.max(1 + difference)),
+        };
+        digits[constraint.first] = first;
+        digits[constraint.second] = second;
+    }
+
+    digits
+        .iter()
+        .fold(0, |number, digit| number * 10 + *digit as u64)
+}
+
+/// Whether MONAD leaves 0 in z after checking number, which must be 14 digits with no zeros
+pub fn is_accepted(program: &[Instruction], number: u64) -> Result<bool> {
+    let digits: Vec<i64> = number
+        .to_string()
+        .chars()
+        .map(|digit| i64::from(digit.to_digit(10).unwrap_or(0)))
+        .collect();
+    if digits.len() != DIGITS || digits.contains(&0) {
+        return Err(anyhow!(
+            "{} is not a model number of {} digits from 1 to 9",
+            number,
+            DIGITS
+        ));
+    }
+
+    Ok(Alu::run(program, digits)?.get(Register::Z) == 0)
+}
+
+/// Work the answer out from the structure of MONAD, then check it by running MONAD
+pub fn find_model_number(program: &[Instruction], preference: Preference) -> Result<u64> {
+    let constraints = constraints(&blocks(program)?)?;
+    log::debug!("Constraints {:?}", constraints);
+
+    let number = model_number(&constraints, preference);
+    if !is_accepted(program, number)? {
+        return Err(anyhow!("MONAD does not accept {}", number));
+    }
+    Ok(number)
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Instruction>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Instruction>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Instruction>> {
+    parse_lines(input.lines())
+}
+
+/// One instruction per line: its name, a register and for everything but inp a register or a
+/// number, separated by spaces.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Instruction>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut program = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+        program.push(parse_instruction(line_index, line)?);
+    }
+
+    Ok(program)
+}
+
+fn parse_instruction(line_index: usize, line: &str) -> ParseResult<Instruction> {
+    let mut column_index = 0;
+    let mut words = line.split(' ').map(|word| {
+        let column = column_index;
+        column_index += word.len() + 1;
+        (column, word)
+    });
+
+    let (_, name) = words.next().unwrap_or((0, ""));
+    let (column, register) = words
+        .next()
+        .ok_or_else(|| ParseError::invalid(line_index, 0, line, "an instruction and a register"))?;
+    let register = parse_register(register)
+        .ok_or_else(|| ParseError::invalid(line_index, column, register, "w, x, y or z"))?;
+
+    let instruction = if name == "inp" {
+        Instruction::Inp(register)
+    } else {
+        let (column, operand) = words.next().ok_or_else(|| {
+            ParseError::invalid(line_index, 0, line, "an instruction and two arguments")
+        })?;
+        let operand = match parse_register(operand) {
+            Some(register) => Operand::Register(register),
+            None => Operand::Number(operand.parse().map_err(|_| {
+                ParseError::invalid(line_index, column, operand, "a register or a number")
+            })?),
+        };
+        match name {
+            "add" => Instruction::Add(register, operand),
+            "mul" => Instruction::Mul(register, operand),
+            "div" => Instruction::Div(register, operand),
+            "mod" => Instruction::Mod(register, operand),
+            "eql" => Instruction::Eql(register, operand),
+            _ => {
+                return Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    name,
+                    "inp, add, mul, div, mod or eql",
+                ))
+            }
+        }
+    };
+    if let Some((column, extra)) = words.next() {
+        return Err(ParseError::invalid(
+            line_index,
+            column,
+            extra,
+            "the end of the instruction",
+        ));
+    }
+
+    Ok(instruction)
+}
+
+fn parse_register(name: &str) -> Option<Register> {
+    match name {
+        "w" => Some(Register::W),
+        "x" => Some(Register::X),
+        "y" => Some(Register::Y),
+        "z" => Some(Register::Z),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        blocks, constraints, find_model_number, is_accepted, model_number, parse_input, parse_str,
+        Alu, Instruction, Operand, Preference, Register, DIGITS,
+    };
+    use aoc_common::Rng;
+
+    // There is no example MONAD, so the example is generator::generate with Rng::new(24)
+    #[test]
+    fn test_parse_input() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(program.len(), 14 * 18);
+        assert_eq!(program[0], Instruction::Inp(Register::W));
+        assert_eq!(
+            program[2],
+            Instruction::Add(Register::X, Operand::Register(Register::Z))
+        );
+        let text: String = program
+            .iter()
+            .map(|instruction| format!("{}\n", instruction))
+            .collect();
+        assert_eq!(
+            text,
+            std::fs::read_to_string("src/example_input.txt").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("inp w\nadd x 2q\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected a register or a number, found \"2q\""
+        );
+        assert!(parse_str("sub x 1").is_err());
+        assert!(parse_str("add v 1").is_err());
+        assert!(parse_str("add x").is_err());
+        assert!(parse_str("inp w x").is_err());
+        assert!(parse_str("inp").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let negate = parse_str("inp x\nmul x -1\n").unwrap();
+        assert_eq!(Alu::run(&negate, [7]).unwrap().get(Register::X), -7);
+
+        let three_times = parse_str("inp z\ninp x\nmul z 3\neql z x\n").unwrap();
+        assert_eq!(Alu::run(&three_times, [2, 6]).unwrap().get(Register::Z), 1);
+        assert_eq!(Alu::run(&three_times, [2, 5]).unwrap().get(Register::Z), 0);
+
+        let binary = parse_str(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\n\
+             add x w\nmod x 2\ndiv w 2\nmod w 2\n",
+        )
+        .unwrap();
+        let alu = Alu::run(&binary, [13]).unwrap();
+        let bits = [Register::W, Register::X, Register::Y, Register::Z].map(|r| alu.get(r));
+        assert_eq!(bits, [1, 1, 0, 1]);
+
+        // Division truncates towards 0
+        let divide = parse_str("inp x\ndiv x 2\n").unwrap();
+        assert_eq!(Alu::run(&divide, [-7]).unwrap().get(Register::X), -3);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert!(Alu::run(&parse_str("inp x\ndiv x 0\n").unwrap(), [1]).is_err());
+        assert!(Alu::run(&parse_str("inp x\nmod x 2\n").unwrap(), [-1]).is_err());
+        assert!(Alu::run(&parse_str("inp x\nmod x y\n").unwrap(), [1]).is_err());
+        assert!(Alu::run(&parse_str("inp x\ninp y\n").unwrap(), [1]).is_err());
+        let overflow = parse_str("inp x\nmul x x\n").unwrap();
+        assert!(Alu::run(&overflow, [i64::MAX]).is_err());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        let blocks = blocks(&program).unwrap();
+        assert_eq!(blocks.len(), DIGITS);
+        let rebuilt: Vec<Instruction> = blocks
+            .iter()
+            .flat_map(|block| block.instructions())
+            .collect();
+        assert_eq!(rebuilt, program);
+
+        let mut changed = program.clone();
+        changed[20] = Instruction::Add(Register::X, Operand::Register(Register::Y));
+        assert!(crate::blocks(&changed).is_err());
+        assert!(crate::blocks(&program[..18 * 13]).is_err());
+    }
+
+    #[test]
+    fn test_constraints_match_alu() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        let constraints = constraints(&blocks(&program).unwrap()).unwrap();
+        assert_eq!(constraints.len(), DIGITS / 2);
+
+        // Half the numbers tried meet every constraint and the other half miss one
+        let mut rng = Rng::new(24);
+        for attempt in 0..100 {
+            let mut digits = [0; DIGITS];
+            for constraint in &constraints {
+                let low = 1.max(1 - constraint.difference);
+                let high = 9.min(9 - constraint.difference);
+                let first = low + rng.range(0..(high - low + 1) as usize) as i64;
+                digits[constraint.first] = first;
+                digits[constraint.second] = first + constraint.difference;
+            }
+            let valid = attempt % 2 == 0;
+            if !valid {
+                let constraint = rng.choose(&constraints);
+                let wrong: Vec<i64> = (1..=9)
+                    .filter(|digit| *digit != digits[constraint.first] + constraint.difference)
+                    .collect();
+                digits[constraint.second] = *rng.choose(&wrong);
+            }
+
+            let number = digits
+                .iter()
+                .fold(0, |number, digit| number * 10 + *digit as u64);
+            assert_eq!(is_accepted(&program, number).unwrap(), valid, "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_model_number_runs_on_alu() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        let constraints = constraints(&blocks(&program).unwrap()).unwrap();
+        let z = |digits: &[i64]| {
+            Alu::run(&program, digits.iter().copied())
+                .unwrap()
+                .get(Register::Z)
+        };
+
+        // Nudging the second digit of any pair by one breaks its constraint, so MONAD rejects it
+        for preference in [Preference::Largest, Preference::Smallest] {
+            let number = model_number(&constraints, preference);
+            let digits: Vec<i64> = number
+                .to_string()
+                .chars()
+                .map(|digit| i64::from(digit.to_digit(10).unwrap()))
+                .collect();
+            assert_eq!(z(&digits), 0, "{}", number);
+
+            for constraint in &constraints {
+                for nudge in [-1, 1] {
+                    let mut nudged = digits.clone();
+                    nudged[constraint.second] += nudge;
+                    if (1..=9).contains(&nudged[constraint.second]) {
+                        assert_ne!(z(&nudged), 0, "{:?}", nudged);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_accepted_invalid() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        assert!(is_accepted(&program, 1234).is_err());
+        assert!(is_accepted(&program, 11111111111110).is_err());
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(
+            find_model_number(&program, Preference::Largest).unwrap(),
+            39499799946293
+        );
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let program = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(
+            find_model_number(&program, Preference::Smallest).unwrap(),
+            17163174611181
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Random starting positions.
+use crate::Rules;
+use aoc_common::Rng;
+
+/// Both players start on a random space of the real board
+pub fn generate(rng: &mut Rng) -> String {
+    (1..=2)
+        .map(|player| {
+            let space = 1 + rng.range(0..Rules::PRACTICE.board_size);
+            format!("Player {} starting position: {}\n", player, space)
+        })
+        .collect()
+}
+
+/// There is nothing to scale, since the input is only ever two starting positions
+pub fn generate_scaled(rng: &mut Rng, _scale: usize) -> String {
+    generate(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, part_two};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(21);
+        for _ in 0..5 {
+            let starts = parse_str(&generate(&mut rng)).unwrap();
+            assert!(starts.iter().all(|start| (1..=10).contains(start)));
+            assert!(part_one(starts).is_ok());
+            assert!(part_two(starts).is_ok());
+        }
+    }
+}
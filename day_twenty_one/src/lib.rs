@@ -0,0 +1,414 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Each player's starting space, from 1 to the board size
+pub type Starts = [usize; 2];
+
+pub struct DayTwentyOne;
+
+impl Solution for DayTwentyOne {
+    const DAY: u8 = 21;
+    type Input = Starts;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(*input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(*input)
+    }
+}
+
+/// How a game of Dirac Dice is played.  Players take turns rolling the die rolls_per_turn
+/// times, move forward around a circular board of spaces numbered from 1 by the total, and
+/// score the number of the space they land on.  The first to winning_score wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub board_size: usize,
+    pub winning_score: usize,
+    pub die_faces: usize,
+    pub rolls_per_turn: usize,
+}
+
+impl Rules {
+    /// The practice game of part one, with a 100 sided deterministic die
+    pub const PRACTICE: Rules = Rules {
+        board_size: 10,
+        winning_score: 1000,
+        die_faces: 100,
+        rolls_per_turn: 3,
+    };
+
+    /// The real game of part two, where every roll of the three sided die splits the universe
+    pub const DIRAC: Rules = Rules {
+        board_size: 10,
+        winning_score: 21,
+        die_faces: 3,
+        rolls_per_turn: 3,
+    };
+
+    fn check(&self, starts: Starts) -> Result<()> {
+        if self.board_size == 0 || self.die_faces == 0 || self.rolls_per_turn == 0 {
+            return Err(anyhow!(
+                "The board, the die and the number of rolls must not be zero in {:?}",
+                self
+            ));
+        }
+        if let Some(start) = starts
+            .iter()
+            .find(|start| !(1..=self.board_size).contains(*start))
+        {
+            return Err(anyhow!(
+                "Space {} is not on a board of {} spaces",
+                start,
+                self.board_size
+            ));
+        }
+        Ok(())
+    }
+
+    /// The space reached by moving steps forward from position
+    fn advance(&self, position: usize, steps: usize) -> usize {
+        (position - 1 + steps) % self.board_size + 1
+    }
+
+    /// Every total one turn of rolls can add up to, with the number of ways to roll it
+    fn roll_totals(&self) -> Result<Vec<(usize, u128)>> {
+        let mut totals = vec![(0, 1)];
+        for _ in 0..self.rolls_per_turn {
+            let mut next: HashMap<usize, u128> = HashMap::new();
+            for (total, ways) in totals {
+                for face in 1..=self.die_faces {
+                    let sum = next.entry(total + face).or_insert(0);
+                    *sum = sum
+                        .checked_add(ways)
+                        .ok_or_else(|| self.too_many_universes())?;
+                }
+            }
+            totals = next.into_iter().collect();
+        }
+        totals.sort_unstable();
+        Ok(totals)
+    }
+
+    fn too_many_universes(&self) -> anyhow::Error {
+        anyhow!(
+            "There are more universes than fit in 128 bits in {:?}",
+            self
+        )
+    }
+}
+
+/// How a game with the deterministic die, which rolls 1, 2, 3 and so on up to its number of
+/// faces and then starts again at 1, ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PracticeGame {
+    /// 0 for the first player, 1 for the second
+    pub winner: usize,
+    pub scores: [usize; 2],
+    pub rolls: usize,
+}
+
+impl PracticeGame {
+    pub fn losing_score(&self) -> usize {
+        self.scores[1 - self.winner]
+    }
+}
+
+pub fn play_practice(starts: Starts, rules: &Rules) -> Result<PracticeGame> {
+    rules.check(starts)?;
+
+    let mut positions = starts;
+    let mut scores = [0, 0];
+    let mut rolls = 0;
+    let mut player = 0;
+    loop {
+        let steps: usize = (0..rules.rolls_per_turn)
+            .map(|roll| (rolls + roll) % rules.die_faces + 1)
+            .sum();
+        rolls += rules.rolls_per_turn;
+        positions[player] = rules.advance(positions[player], steps);
+        scores[player] += positions[player];
+
+        if scores[player] >= rules.winning_score {
+            return Ok(PracticeGame {
+                winner: player,
+                scores,
+                rolls,
+            });
+        }
+        player = 1 - player;
+    }
+}
+
+/// The highest winning score count_wins plays to.  Every turn goes one level deeper into the
+/// multiverse, and each scores at least 1
+pub const MAX_DIRAC_WINNING_SCORE: usize = 1000;
+
+/// The number of universes each player wins in, when every roll of the die splits the
+/// universe into one for each face
+pub fn count_wins(starts: Starts, rules: &Rules) -> Result<[u128; 2]> {
+    rules.check(starts)?;
+    if rules.winning_score > MAX_DIRAC_WINNING_SCORE {
+        return Err(anyhow!(
+            "A winning score above {} is too deep to count universes for in {:?}",
+            MAX_DIRAC_WINNING_SCORE,
+            rules
+        ));
+    }
+
+    let mut multiverse = Multiverse {
+        rules,
+        roll_totals: rules.roll_totals()?,
+        known: HashMap::new(),
+    };
+    multiverse.wins(starts, [0, 0])
+}
+
+/// Remembers how many wins each game state leads to, since the same states are reached in a
+/// huge number of universes
+struct Multiverse<'a> {
+    rules: &'a Rules,
+    roll_totals: Vec<(usize, u128)>,
+    known: HashMap<([usize; 2], [usize; 2]), [u128; 2]>,
+}
+
+impl Multiverse<'_> {
+    /// Wins for the player about to move and then the other one.  positions and scores are in
+    /// the same order
+    fn wins(&mut self, positions: [usize; 2], scores: [usize; 2]) -> Result<[u128; 2]> {
+        if let Some(wins) = self.known.get(&(positions, scores)) {
+            return Ok(*wins);
+        }
+
+        let mut wins: [u128; 2] = [0, 0];
+        for index in 0..self.roll_totals.len() {
+            let (total, ways) = self.roll_totals[index];
+            let position = self.rules.advance(positions[0], total);
+            let score = scores[0] + position;
+            let won = if score >= self.rules.winning_score {
+                [Some(ways), Some(0)]
+            } else {
+                // The other player moves next, so is first in the state after this
+                let [other_wins, mover_wins] =
+                    self.wins([positions[1], position], [scores[1], score])?;
+                [ways.checked_mul(mover_wins), ways.checked_mul(other_wins)]
+            };
+            for (player_wins, won) in wins.iter_mut().zip(won) {
+                *player_wins = won
+                    .and_then(|won| player_wins.checked_add(won))
+                    .ok_or_else(|| self.rules.too_many_universes())?;
+            }
+        }
+
+        self.known.insert((positions, scores), wins);
+        Ok(wins)
+    }
+}
+
+/// The losing score times the number of rolls in the practice game
+pub fn part_one(starts: Starts) -> Result<usize> {
+    let game = play_practice(starts, &Rules::PRACTICE)?;
+    Ok(game.losing_score() * game.rolls)
+}
+
+/// The number of universes the player who wins in more of them wins in
+pub fn part_two(starts: Starts) -> Result<u64> {
+    let most = count_wins(starts, &Rules::DIRAC)?
+        .into_iter()
+        .max()
+        .unwrap_or(0);
+    u64::try_from(most).map_err(|_| anyhow!("{} wins do not fit in 64 bits", most))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Starts>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Starts>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Starts> {
+    parse_lines(input.lines())
+}
+
+/// `Player <n> starting position: <space>` for players 1 and 2 in order.  Blank lines are
+/// skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Starts>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, line)| !line.as_ref().trim().is_empty());
+
+    let mut starts = [0; 2];
+    let mut next_line_index = 0;
+    for (player, start) in starts.iter_mut().enumerate() {
+        let prefix = format!("Player {} starting position: ", player + 1);
+        let expected = format!("{}<space>", prefix);
+        let (line_index, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end(next_line_index, expected.as_str()))?;
+        let line = line.as_ref().trim();
+
+        let space = line
+            .strip_prefix(&prefix)
+            .ok_or_else(|| ParseError::invalid(line_index, 0, line, expected.as_str()))?;
+        *start = space
+            .parse()
+            .ok()
+            .filter(|space| *space > 0)
+            .ok_or_else(|| {
+                ParseError::invalid(line_index, prefix.len(), space, "a space from 1")
+            })?;
+        next_line_index = line_index + 1;
+    }
+
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_wins, parse_input, parse_str, part_one, part_two, play_practice, PracticeGame, Rules,
+        MAX_DIRAC_WINNING_SCORE,
+    };
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("src/example_input.txt").unwrap(), [4, 8]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error =
+            parse_str("Player 1 starting position: 4\nPlayer 2 starting position: x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 29: expected a space from 1, found \"x\""
+        );
+        assert!(parse_str("Player 2 starting position: 4\n").is_err());
+        assert!(parse_str("Player 1 starting position: 4\n").is_err());
+        assert!(parse_str("Player 1 starting position: 0\n").is_err());
+    }
+
+    #[test]
+    fn test_play_practice() {
+        let game = play_practice([4, 8], &Rules::PRACTICE).unwrap();
+        assert_eq!(
+            game,
+            PracticeGame {
+                winner: 0,
+                scores: [1000, 745],
+                rolls: 993
+            }
+        );
+        assert_eq!(game.losing_score(), 745);
+        assert!(play_practice([4, 11], &Rules::PRACTICE).is_err());
+    }
+
+    #[test]
+    fn test_count_wins() {
+        let wins = count_wins([4, 8], &Rules::DIRAC).unwrap();
+        assert_eq!(wins, [444356092776315, 341960390180808]);
+
+        // A one sided die leaves a single universe, which plays like the deterministic die
+        let rules = Rules {
+            die_faces: 1,
+            ..Rules::DIRAC
+        };
+        let game = play_practice([4, 8], &rules).unwrap();
+        let mut expected = [0, 0];
+        expected[game.winner] = 1;
+        assert_eq!(count_wins([4, 8], &rules).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_variants() {
+        // Winning with the first move: every space scores at least 1, so wherever the four
+        // ways to roll two two-sided dice land, the first player wins in all four universes
+        let rules = Rules {
+            board_size: 4,
+            winning_score: 1,
+            die_faces: 2,
+            rolls_per_turn: 2,
+        };
+        assert_eq!(count_wins([1, 1], &rules).unwrap(), [4, 0]);
+
+        let zero_faces = Rules {
+            die_faces: 0,
+            ..Rules::DIRAC
+        };
+        assert!(count_wins([1, 1], &zero_faces).is_err());
+    }
+
+    #[test]
+    fn test_count_wins_limits() {
+        // With 27 universes a turn, a race to 60 runs past 128 bits
+        let long_game = Rules {
+            winning_score: 60,
+            ..Rules::DIRAC
+        };
+        let error = count_wins([4, 8], &long_game).unwrap_err();
+        assert!(error.to_string().contains("128 bits"), "{}", error);
+
+        let many_rolls = Rules {
+            rolls_per_turn: 100,
+            ..Rules::DIRAC
+        };
+        assert!(count_wins([4, 8], &many_rolls).is_err());
+
+        // A single universe never overflows, but a game this long is too deep to play out
+        let one_face = Rules {
+            die_faces: 1,
+            ..Rules::DIRAC
+        };
+        let deep = Rules {
+            winning_score: MAX_DIRAC_WINNING_SCORE + 1,
+            ..one_face
+        };
+        assert!(count_wins([4, 8], &deep).is_err());
+        let deepest = Rules {
+            winning_score: MAX_DIRAC_WINNING_SCORE,
+            ..one_face
+        };
+        let game = play_practice([4, 8], &deepest).unwrap();
+        let mut expected = [0, 0];
+        expected[game.winner] = 1;
+        assert_eq!(count_wins([4, 8], &deepest).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        assert_eq!(part_one([4, 8]).unwrap(), 739785);
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        assert_eq!(part_two([4, 8]).unwrap(), 444356092776315);
+    }
+}
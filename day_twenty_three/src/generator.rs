@@ -0,0 +1,58 @@
+//! Random burrows.
+use aoc_common::Rng;
+
+/// Two of each amphipod shuffled into the rooms of an empty hallway, never already organized
+pub fn generate(rng: &mut Rng) -> String {
+    let mut letters: Vec<char> = "AABBCCDD".chars().collect();
+    rng.shuffle(&mut letters);
+    while letters == ['A', 'B', 'C', 'D', 'A', 'B', 'C', 'D'] {
+        rng.shuffle(&mut letters);
+    }
+
+    let row = |letters: &[char]| {
+        letters
+            .iter()
+            .map(|letter| letter.to_string())
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(&letters[..4]),
+        row(&letters[4..])
+    )
+}
+
+/// There is nothing to scale, since the input is only ever the same small burrow
+pub fn generate_scaled(rng: &mut Rng, _scale: usize) -> String {
+    generate(rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{organize, parse_str};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(23);
+        for _ in 0..2 {
+            let burrow = parse_str(&generate(&mut rng)).unwrap();
+            assert!(!burrow.is_organized());
+
+            for burrow in [burrow, burrow.unfolded().unwrap()] {
+                let plan = organize(&burrow).unwrap();
+                let mut replayed = burrow;
+                let mut energy = 0;
+                for a_move in &plan.moves {
+                    assert!(replayed.moves().contains(a_move));
+                    replayed = replayed.after(a_move);
+                    energy += a_move.energy;
+                }
+                assert!(replayed.is_organized());
+                assert_eq!(energy, plan.energy);
+            }
+        }
+    }
+}
@@ -0,0 +1,560 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTwentyThree;
+
+impl Solution for DayTwentyThree {
+    const DAY: u8 = 23;
+    type Input = Burrow;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(organize(input)?.energy)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(organize(&input.unfolded()?)?.energy)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    const ALL: [Amphipod; 4] = [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ];
+
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+
+    pub fn letter(&self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
+    /// Energy used to move one step
+    pub fn energy(&self) -> usize {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    /// The room it belongs in, counted from the left
+    pub fn room(&self) -> usize {
+        *self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// Counted from the left end of the hallway
+    Hallway(usize),
+    /// Rooms are counted from the left and slots from the top
+    Room { room: usize, slot: usize },
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Hallway(position) => write!(f, "hallway {}", position),
+            Location::Room { room, slot } => write!(f, "room {} slot {}", room, slot),
+        }
+    }
+}
+
+/// One amphipod walking from one place to another without stopping
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub amphipod: Amphipod,
+    pub from: Location,
+    pub to: Location,
+    pub energy: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from {} to {} for {}",
+            self.amphipod.letter(),
+            self.from,
+            self.to,
+            self.energy
+        )
+    }
+}
+
+const HALLWAY_LEN: usize = 11;
+const ROOMS: usize = 4;
+const MAX_DEPTH: usize = 4;
+/// Places in the hallway where amphipods may stop, which is everywhere but outside a room
+const STOPS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+
+/// The hallway position just outside room
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Where every amphipod is.  Each cell is a single byte, 0 for empty and 1 to 4 for an
+/// amphipod, so burrows are cheap to copy, compare and hash while searching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Burrow {
+    depth: usize,
+    /// The hallway from left to right, then each room from top to bottom.  Rooms always take
+    /// MAX_DEPTH cells, whatever their depth
+    cells: [u8; HALLWAY_LEN + ROOMS * MAX_DEPTH],
+}
+
+impl Burrow {
+    /// The number of amphipods each room holds
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn index(location: Location) -> usize {
+        match location {
+            Location::Hallway(position) => position,
+            Location::Room { room, slot } => HALLWAY_LEN + room * MAX_DEPTH + slot,
+        }
+    }
+
+    pub fn get(&self, location: Location) -> Option<Amphipod> {
+        match self.cells[Burrow::index(location)] {
+            0 => None,
+            cell => Some(Amphipod::ALL[cell as usize - 1]),
+        }
+    }
+
+    fn set(&mut self, location: Location, amphipod: Option<Amphipod>) {
+        self.cells[Burrow::index(location)] = amphipod.map_or(0, |amphipod| amphipod as u8 + 1);
+    }
+
+    fn room(&self, room: usize) -> impl Iterator<Item = Option<Amphipod>> + '_ {
+        (0..self.depth).map(move |slot| self.get(Location::Room { room, slot }))
+    }
+
+    /// Whether every room holds only the amphipods that belong there, and is full
+    pub fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.room(room)
+                .all(|amphipod| amphipod.is_some_and(|amphipod| amphipod.room() == room))
+        })
+    }
+
+    /// The deepest empty slot of room, if only amphipods that belong there are in it
+    fn open_slot(&self, room: usize) -> Option<usize> {
+        let mut open = None;
+        for (slot, amphipod) in self.room(room).enumerate() {
+            match amphipod {
+                None => open = Some(slot),
+                Some(amphipod) if amphipod.room() != room => return None,
+                Some(_) => (),
+            }
+        }
+        open
+    }
+
+    /// Whether every hallway position from from to to is empty, apart from from itself
+    fn hallway_clear(&self, from: usize, to: usize) -> bool {
+        (from.min(to)..=from.max(to))
+            .filter(|position| *position != from)
+            .all(|position| self.get(Location::Hallway(position)).is_none())
+    }
+
+    /// Every move allowed from here.  Amphipods in the hallway can only move into their own
+    /// room once it holds nobody else, and amphipods only leave a room if they or someone
+    /// under them do not belong there
+    pub fn moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        for position in 0..HALLWAY_LEN {
+            let Some(amphipod) = self.get(Location::Hallway(position)) else {
+                continue;
+            };
+            let room = amphipod.room();
+            if let Some(slot) = self.open_slot(room) {
+                if self.hallway_clear(position, door(room)) {
+                    moves.push(Move {
+                        amphipod,
+                        from: Location::Hallway(position),
+                        to: Location::Room { room, slot },
+                        energy: (position.abs_diff(door(room)) + slot + 1) * amphipod.energy(),
+                    });
+                }
+            }
+        }
+
+        for room in 0..ROOMS {
+            let Some((slot, amphipod)) = self
+                .room(room)
+                .enumerate()
+                .find_map(|(slot, amphipod)| amphipod.map(|amphipod| (slot, amphipod)))
+            else {
+                continue;
+            };
+            let settled = self
+                .room(room)
+                .skip(slot)
+                .all(|amphipod| amphipod.is_some_and(|amphipod| amphipod.room() == room));
+            if settled {
+                continue;
+            }
+
+            for position in STOPS {
+                if self.hallway_clear(door(room), position) {
+                    moves.push(Move {
+                        amphipod,
+                        from: Location::Room { room, slot },
+                        to: Location::Hallway(position),
+                        energy: (door(room).abs_diff(position) + slot + 1) * amphipod.energy(),
+                    });
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// The burrow after a_move, which is expected to be one of [`Self::moves`]
+    pub fn after(&self, a_move: &Move) -> Burrow {
+        let mut burrow = *self;
+        burrow.set(a_move.from, None);
+        burrow.set(a_move.to, Some(a_move.amphipod));
+        burrow
+    }
+
+    /// The full burrow of part two, with the two folded lines of the diagram put back between
+    /// the two lines of amphipods.  Only a burrow with rooms of depth 2 can be unfolded
+    pub fn unfolded(&self) -> Result<Burrow> {
+        if self.depth != 2 {
+            return Err(anyhow!(
+                "Only a burrow with rooms of depth 2 can be unfolded, not {}",
+                self.depth
+            ));
+        }
+
+        let folded = ["DCBA", "DBAC"];
+        let mut burrow = *self;
+        burrow.depth = 4;
+        for room in 0..ROOMS {
+            let bottom = self.get(Location::Room { room, slot: 1 });
+            burrow.set(Location::Room { room, slot: 3 }, bottom);
+            for (slot, line) in folded.iter().enumerate() {
+                let amphipod = Amphipod::from_letter(line.as_bytes()[room] as char);
+                burrow.set(
+                    Location::Room {
+                        room,
+                        slot: slot + 1,
+                    },
+                    amphipod,
+                );
+            }
+        }
+
+        Ok(burrow)
+    }
+}
+
+/// The diagram the puzzle input is drawn as
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = |location| self.get(location).map_or('.', |amphipod| amphipod.letter());
+
+        writeln!(f, "#############")?;
+        let hallway: String = (0..HALLWAY_LEN)
+            .map(|position| letter(Location::Hallway(position)))
+            .collect();
+        writeln!(f, "#{}#", hallway)?;
+        for slot in 0..self.depth {
+            let rooms: Vec<String> = (0..ROOMS)
+                .map(|room| letter(Location::Room { room, slot }).to_string())
+                .collect();
+            let edge = if slot == 0 { "##" } else { "  " };
+            writeln!(f, "{}#{}#{}", edge, rooms.join("#"), edge.trim())?;
+        }
+        writeln!(f, "  #########")
+    }
+}
+
+/// The cheapest way to organize a burrow
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub energy: usize,
+    /// In the order they are made
+    pub moves: Vec<Move>,
+}
+
+/// Search burrows in order of the energy used to reach them, so the first organized one found
+/// is reached the cheapest way
+pub fn organize(burrow: &Burrow) -> Result<Plan> {
+    let mut energies = HashMap::from([(*burrow, 0)]);
+    // The burrow each burrow was cheapest to reach from, and the move that did it
+    let mut previous: HashMap<Burrow, (Burrow, Move)> = HashMap::new();
+    let mut to_visit = BinaryHeap::from([Reverse((0, *burrow))]);
+
+    while let Some(Reverse((energy, current))) = to_visit.pop() {
+        if current.is_organized() {
+            log::debug!("Reached {} burrows", energies.len());
+            let mut moves = Vec::new();
+            let mut step = current;
+            while let Some((before, a_move)) = previous.get(&step) {
+                moves.push(*a_move);
+                step = *before;
+            }
+            moves.reverse();
+            return Ok(Plan { energy, moves });
+        }
+        if energy > energies[&current] {
+            continue;
+        }
+
+        for a_move in current.moves() {
+            let next = current.after(&a_move);
+            let next_energy = energy + a_move.energy;
+            if energies.get(&next).is_none_or(|known| next_energy < *known) {
+                energies.insert(next, next_energy);
+                previous.insert(next, (current, a_move));
+                to_visit.push(Reverse((next_energy, next)));
+            }
+        }
+    }
+
+    Err(anyhow!("The amphipods cannot be organized:\n{}", burrow))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Burrow>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Burrow>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Burrow> {
+    parse_lines(input.lines())
+}
+
+/// The diagram of the burrow: a wall, the hallway, a line for each slot of the rooms and then
+/// the bottom wall.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Burrow>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, line)| !line.as_ref().trim().is_empty());
+    let mut burrow = Burrow {
+        depth: 0,
+        cells: [0; HALLWAY_LEN + ROOMS * MAX_DEPTH],
+    };
+    let cell =
+        |line_index: usize, column_index: usize, line: &str| -> ParseResult<Option<Amphipod>> {
+            match line.chars().nth(column_index) {
+                Some('.') => Ok(None),
+                Some(letter) if Amphipod::from_letter(letter).is_some() => {
+                    Ok(Amphipod::from_letter(letter))
+                }
+                found => Err(ParseError::invalid(
+                    line_index,
+                    column_index,
+                    found.map_or(String::new(), String::from),
+                    "an amphipod A to D or .",
+                )),
+            }
+        };
+
+    let (line_index, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::unexpected_end(0, "the top wall of the burrow"))?;
+    if line.as_ref().trim_end() != "#############" {
+        return Err(ParseError::invalid(
+            line_index,
+            0,
+            line.as_ref(),
+            "#############",
+        ));
+    }
+
+    let (line_index, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::unexpected_end(line_index + 1, "the hallway"))?;
+    for position in 0..HALLWAY_LEN {
+        let amphipod = cell(line_index, position + 1, line.as_ref())?;
+        burrow.set(Location::Hallway(position), amphipod);
+    }
+
+    let mut last_line_index = line_index;
+    for (line_index, line) in lines {
+        let line = line.as_ref().trim_end();
+        last_line_index = line_index;
+        if line.trim() == "#########" {
+            break;
+        }
+        if burrow.depth == MAX_DEPTH {
+            return Err(ParseError::invalid(line_index, 0, line, "  #########"));
+        }
+
+        for room in 0..ROOMS {
+            let amphipod = cell(line_index, door(room) + 1, line)?;
+            let slot = burrow.depth;
+            burrow.set(Location::Room { room, slot }, amphipod);
+        }
+        burrow.depth += 1;
+    }
+    if burrow.depth == 0 {
+        return Err(ParseError::unexpected_end(
+            last_line_index + 1,
+            "a line of amphipods in rooms",
+        ));
+    }
+
+    Ok(burrow)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{organize, parse_input, parse_str, Amphipod, Location, Move};
+
+    #[test]
+    fn test_parse_input() {
+        let burrow = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(burrow.depth(), 2);
+        assert_eq!(
+            burrow.get(Location::Room { room: 1, slot: 1 }),
+            Some(Amphipod::Desert)
+        );
+        assert_eq!(burrow.get(Location::Hallway(0)), None);
+        assert_eq!(
+            burrow.to_string(),
+            std::fs::read_to_string("src/example_input.txt").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("#############\n#...........#\n###B#C#B#E###\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 10: expected an amphipod A to D or ., found \"E\""
+        );
+        assert!(parse_str("#############\n#...........#\n  #########\n").is_err());
+        assert!(parse_str("#############\n#....\n").is_err());
+        assert!(parse_str("").is_err());
+    }
+
+    #[test]
+    fn test_unfolded() {
+        let burrow = parse_input("src/example_input.txt").unwrap();
+        let unfolded = burrow.unfolded().unwrap();
+        assert_eq!(
+            unfolded.to_string(),
+            "#############\n\
+             #...........#\n\
+             ###B#C#B#D###\n  \
+             #D#C#B#A#\n  \
+             #D#B#A#C#\n  \
+             #A#D#C#A#\n  \
+             #########\n"
+        );
+        assert!(unfolded.unfolded().is_err());
+    }
+
+    #[test]
+    fn test_moves() {
+        let burrow = parse_input("src/example_input.txt").unwrap();
+        // Only the top amphipod of each room can leave, to any of the seven stops
+        assert_eq!(burrow.moves().len(), 28);
+
+        let a_move = Move {
+            amphipod: Amphipod::Bronze,
+            from: Location::Room { room: 2, slot: 0 },
+            to: Location::Hallway(3),
+            energy: 40,
+        };
+        assert!(burrow.moves().contains(&a_move));
+        assert_eq!(
+            a_move.to_string(),
+            "B from room 2 slot 0 to hallway 3 for 40"
+        );
+        let after = burrow.after(&a_move);
+        assert_eq!(after.get(Location::Hallway(3)), Some(Amphipod::Bronze));
+        // Nobody can get past it, and it cannot go home while its room holds others
+        assert!(after.moves().iter().all(|a_move| {
+            a_move.from != Location::Hallway(3)
+                && !(a_move.from == Location::Room { room: 0, slot: 0 }
+                    && a_move.to == Location::Hallway(5))
+        }));
+        assert_eq!(after.moves().len(), 10);
+    }
+
+    #[test]
+    fn test_organize_example() {
+        let burrow = parse_input("src/example_input.txt").unwrap();
+        let plan = organize(&burrow).unwrap();
+        assert_eq!(plan.energy, 12521);
+        assert_eq!(
+            plan.moves.iter().map(|a_move| a_move.energy).sum::<usize>(),
+            12521
+        );
+        let organized = plan
+            .moves
+            .iter()
+            .fold(burrow, |burrow, a_move| burrow.after(a_move));
+        assert!(organized.is_organized());
+        assert!(!burrow.is_organized());
+        assert_eq!(plan.moves.len(), 12);
+    }
+
+    #[test]
+    fn test_organize_unfolded_example() {
+        let burrow = parse_input("src/example_input.txt").unwrap();
+        let plan = organize(&burrow.unfolded().unwrap()).unwrap();
+        assert_eq!(plan.energy, 44169);
+    }
+}
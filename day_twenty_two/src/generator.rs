@@ -0,0 +1,57 @@
+//! Random reboot steps.
+use crate::{Cuboid, Step};
+use aoc_common::Rng;
+
+/// small steps inside the initialization region followed by large steps all over, like the
+/// real reboot
+pub fn generate(rng: &mut Rng, small: usize, large: usize) -> String {
+    let mut steps: Vec<Step> = (0..small).map(|_| random_step(rng, 50, 45)).collect();
+    steps.extend((0..large).map(|_| random_step(rng, 100_000, 40_000)));
+
+    steps.iter().map(|step| format!("{}\n", step)).collect()
+}
+
+/// A scale of 1 is about the size of the real reboot.  Only the large steps grow
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 20, 400 * scale)
+}
+
+/// A step with every side at most max_size long and every cube within reach of 0 on every
+/// axis.  max_size must be no more than the 2 * reach + 1 cubes that are within reach
+pub fn random_step(rng: &mut Rng, reach: i64, max_size: i64) -> Step {
+    let mut min = [0; 3];
+    let mut max = [0; 3];
+    for axis in 0..3 {
+        let size = 1 + rng.range(0..max_size as usize) as i64;
+        min[axis] = -reach + rng.range(0..(2 * reach + 2 - size) as usize) as i64;
+        max[axis] = min[axis] + size - 1;
+    }
+
+    Step {
+        on: rng.chance(0.6),
+        cuboid: Cuboid { min, max },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{lit_cubes, parse_str, Cuboid};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(22);
+        let steps = parse_str(&generate(&mut rng, 10, 30)).unwrap();
+        assert_eq!(steps.len(), 40);
+        let region = Cuboid::INITIALIZATION;
+        assert!(steps[..10]
+            .iter()
+            .all(|step| step.cuboid.intersection(&region) == Some(step.cuboid)));
+
+        // Every cube inside the region is also counted without one
+        let inside = lit_cubes(&steps, Some(&region)).unwrap();
+        assert!(lit_cubes(&steps, None).unwrap() >= inside);
+        assert!(inside > 0);
+    }
+}
@@ -0,0 +1,417 @@
+pub mod generator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct DayTwentyTwo;
+
+impl Solution for DayTwentyTwo {
+    const DAY: u8 = 22;
+    type Input = Vec<Step>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        lit_cubes(input, Some(&Cuboid::INITIALIZATION))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        lit_cubes(input, None)
+    }
+}
+
+/// Every cube from min to max along each of x, y and z, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: [i64; 3],
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    /// The region part one is limited to
+    pub const INITIALIZATION: Cuboid = Cuboid {
+        min: [-50; 3],
+        max: [50; 3],
+    };
+
+    /// The cubes in both, if there are any
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = [0, 1, 2].map(|axis| self.min[axis].max(other.min[axis]));
+        let max = [0, 1, 2].map(|axis| self.max[axis].min(other.max[axis]));
+        if (0..3).all(|axis| min[axis] <= max[axis]) {
+            Some(Cuboid { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// The number of cubes, if it fits in an i128.  Each side fits, but all three of the
+    /// widest cuboid do not
+    pub fn volume(&self) -> Option<i128> {
+        (0..3)
+            .map(|axis| i128::from(self.max[axis]) - i128::from(self.min[axis]) + 1)
+            .try_fold(1i128, |volume, side| volume.checked_mul(side))
+    }
+}
+
+/// `x=<min>..<max>,y=<min>..<max>,z=<min>..<max>`
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "x={}..{},y={}..{},z={}..{}",
+            self.min[0], self.max[0], self.min[1], self.max[1], self.min[2], self.max[2]
+        )
+    }
+}
+
+/// Turn every cube of a cuboid on or off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.on { "on" } else { "off" };
+        write!(f, "{} {}", state, self.cuboid)
+    }
+}
+
+/// The lit cubes as a sum of cuboids that each count once, positively or negatively.
+///
+/// Adding a cuboid first cancels out the part of it that overlaps everything counted so far by
+/// adding each overlap with the opposite sign, so those cubes end up counted zero times.  An on
+/// step then counts the whole cuboid once more, and an off step leaves it at zero
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Reactor {
+    /// The number of times each cuboid counts.  Never 0
+    counts: HashMap<Cuboid, i64>,
+}
+
+impl Reactor {
+    pub fn new() -> Self {
+        Reactor::default()
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        let mut changes: HashMap<Cuboid, i64> = HashMap::new();
+        for (cuboid, count) in &self.counts {
+            if let Some(overlap) = cuboid.intersection(&step.cuboid) {
+                *changes.entry(overlap).or_insert(0) -= count;
+            }
+        }
+        if step.on {
+            *changes.entry(step.cuboid).or_insert(0) += 1;
+        }
+
+        for (cuboid, change) in changes {
+            let count = self.counts.entry(cuboid).or_insert(0);
+            *count += change;
+            if *count == 0 {
+                self.counts.remove(&cuboid);
+            }
+        }
+    }
+
+    /// The number of signed cuboids kept, which is what the time to apply a step depends on
+    pub fn cuboids(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// None if the count does not fit in an i128
+    pub fn lit_cubes(&self) -> Option<i128> {
+        self.counts.iter().try_fold(0i128, |lit, (cuboid, count)| {
+            lit.checked_add(cuboid.volume()?.checked_mul(i128::from(*count))?)
+        })
+    }
+}
+
+/// The number of cubes lit after every step, only looking inside region if there is one
+pub fn lit_cubes(steps: &[Step], region: Option<&Cuboid>) -> Result<u64> {
+    let mut reactor = Reactor::new();
+    for step in steps {
+        let cuboid = match region {
+            Some(region) => match step.cuboid.intersection(region) {
+                Some(cuboid) => cuboid,
+                None => continue,
+            },
+            None => step.cuboid,
+        };
+        reactor.apply(&Step {
+            on: step.on,
+            cuboid,
+        });
+    }
+    log::debug!("{} signed cuboids at the end", reactor.cuboids());
+
+    let lit = reactor
+        .lit_cubes()
+        .ok_or_else(|| anyhow!("The lit cubes do not fit in 128 bits"))?;
+    u64::try_from(lit).map_err(|_| anyhow!("{} lit cubes do not fit in 64 bits", lit))
+}
+
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Step>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(input_path)?;
+
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Step>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Step>> {
+    parse_lines(input.lines())
+}
+
+/// One `on` or `off` step per line, followed by its cuboid.  Blank lines are skipped
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Step>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut steps = Vec::new();
+    for (line_index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref().trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (state, cuboid) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::invalid(line_index, 0, line, "on or off and a cuboid"))?;
+        let on = match state {
+            "on" => true,
+            "off" => false,
+            _ => return Err(ParseError::invalid(line_index, 0, state, "on or off")),
+        };
+        steps.push(Step {
+            on,
+            cuboid: parse_cuboid(line_index, state.len() + 1, cuboid)?,
+        });
+    }
+
+    Ok(steps)
+}
+
+/// column_index is where cuboid starts in the line
+fn parse_cuboid(line_index: usize, column_index: usize, cuboid: &str) -> ParseResult<Cuboid> {
+    let mut min = [0; 3];
+    let mut max = [0; 3];
+    let mut ranges = cuboid.split(',');
+    let mut range_column = column_index;
+    for (axis, name) in ["x", "y", "z"].into_iter().enumerate() {
+        let expected = format!("{}=<min>..<max>", name);
+        let range = ranges.next().ok_or_else(|| {
+            ParseError::invalid(line_index, column_index, cuboid, expected.as_str())
+        })?;
+        let invalid = || ParseError::invalid(line_index, range_column, range, expected.as_str());
+
+        let (from, to) = range
+            .strip_prefix(name)
+            .and_then(|range| range.strip_prefix('='))
+            .and_then(|range| range.split_once(".."))
+            .ok_or_else(invalid)?;
+        let from: i64 = from.parse().map_err(|_| invalid())?;
+        let to: i64 = to.parse().map_err(|_| invalid())?;
+        if from > to {
+            return Err(invalid());
+        }
+        (min[axis], max[axis]) = (from, to);
+        range_column += range.len() + 1;
+    }
+    if let Some(extra) = ranges.next() {
+        return Err(ParseError::invalid(
+            line_index,
+            range_column,
+            extra,
+            "the end of the cuboid",
+        ));
+    }
+
+    Ok(Cuboid { min, max })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::random_step;
+    use crate::{lit_cubes, parse_input, parse_str, Cuboid, Reactor, Step};
+    use aoc_common::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_parse_input() {
+        let steps = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(steps.len(), 4);
+        assert_eq!(
+            steps[2],
+            Step {
+                on: false,
+                cuboid: Cuboid {
+                    min: [9, 9, 9],
+                    max: [11, 11, 11]
+                }
+            }
+        );
+        assert_eq!(steps[0].to_string(), "on x=10..12,y=10..12,z=10..12");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = parse_str("on x=1..2,y=3..4,z=5..x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 18: expected z=<min>..<max>, found \"z=5..x\""
+        );
+        assert!(parse_str("toggle x=1..2,y=3..4,z=5..6").is_err());
+        assert!(parse_str("on x=1..2,y=3..4").is_err());
+        assert!(parse_str("on x=1..2,y=3..4,z=5..6,w=7..8").is_err());
+        assert!(parse_str("on x=2..1,y=3..4,z=5..6").is_err());
+    }
+
+    #[test]
+    fn test_intersection() {
+        let first = Cuboid {
+            min: [0, 0, 0],
+            max: [3, 3, 3],
+        };
+        let second = Cuboid {
+            min: [3, -1, 2],
+            max: [5, 1, 2],
+        };
+        assert_eq!(
+            first.intersection(&second),
+            Some(Cuboid {
+                min: [3, 0, 2],
+                max: [3, 1, 2]
+            })
+        );
+        assert_eq!(first.volume(), Some(64));
+        let apart = Cuboid {
+            min: [4, 0, 0],
+            max: [4, 0, 0],
+        };
+        assert_eq!(first.intersection(&apart), None);
+    }
+
+    #[test]
+    fn test_volume_limits() {
+        let widest = parse_str(
+            "on x=-9223372036854775808..9223372036854775807,y=0..0,z=-9223372036854775808..9223372036854775807",
+        )
+        .unwrap();
+        assert_eq!(widest[0].cuboid.volume(), None);
+        assert!(lit_cubes(&widest, None).is_err());
+        assert_eq!(
+            lit_cubes(&widest, Some(&Cuboid::INITIALIZATION)).unwrap(),
+            101 * 101
+        );
+
+        let wide =
+            parse_str("on x=-9223372036854775808..9223372036854775807,y=0..0,z=0..0").unwrap();
+        assert_eq!(wide[0].cuboid.volume(), Some(1 << 64));
+        assert!(lit_cubes(&wide, None).is_err());
+    }
+
+    #[test]
+    fn test_reactor() {
+        let steps = parse_input("src/example_input.txt").unwrap();
+        let mut reactor = Reactor::new();
+        let lit: Vec<i128> = steps
+            .iter()
+            .map(|step| {
+                reactor.apply(step);
+                reactor.lit_cubes().unwrap()
+            })
+            .collect();
+        assert_eq!(lit, vec![27, 46, 38, 39]);
+    }
+
+    #[test]
+    fn test_matches_every_cube() {
+        let mut rng = Rng::new(22);
+        let mut steps: Vec<Step> = (0..30).map(|_| random_step(&mut rng, 8, 5)).collect();
+        // Far enough out that part one ignores it
+        steps.push(Step {
+            on: true,
+            cuboid: Cuboid {
+                min: [51, 0, 0],
+                max: [60, 0, 0],
+            },
+        });
+
+        let mut lit = HashSet::new();
+        for step in &steps[..30] {
+            for x in step.cuboid.min[0]..=step.cuboid.max[0] {
+                for y in step.cuboid.min[1]..=step.cuboid.max[1] {
+                    for z in step.cuboid.min[2]..=step.cuboid.max[2] {
+                        if step.on {
+                            lit.insert([x, y, z]);
+                        } else {
+                            lit.remove(&[x, y, z]);
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            lit_cubes(&steps, Some(&Cuboid::INITIALIZATION)).unwrap(),
+            lit.len() as u64
+        );
+        assert_eq!(lit_cubes(&steps, None).unwrap(), lit.len() as u64 + 10);
+    }
+
+    #[test]
+    fn test_part_one_example() {
+        let steps = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(
+            lit_cubes(&steps, Some(&Cuboid::INITIALIZATION)).unwrap(),
+            39
+        );
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        let steps = parse_input("src/example_input.txt").unwrap();
+        assert_eq!(lit_cubes(&steps, None).unwrap(), 39);
+    }
+
+    #[test]
+    fn test_part_one_larger_example() {
+        // The last two steps lie entirely outside the initialization region
+        let steps = parse_input("src/larger_example_input.txt").unwrap();
+        assert_eq!(steps.len(), 22);
+        assert_eq!(
+            lit_cubes(&steps, Some(&Cuboid::INITIALIZATION)).unwrap(),
+            590784
+        );
+    }
+
+    #[test]
+    fn test_part_two_larger_example() {
+        let steps = parse_input("src/part_two_example_input.txt").unwrap();
+        assert_eq!(steps.len(), 60);
+        assert_eq!(
+            lit_cubes(&steps, Some(&Cuboid::INITIALIZATION)).unwrap(),
+            474140
+        );
+        assert_eq!(lit_cubes(&steps, None).unwrap(), 2758514936282235);
+    }
+}
@@ -0,0 +1,56 @@
+//! Random planned courses.  The submarine never goes above the surface.
+use aoc_common::Rng;
+
+/// commands of forward, down or up with a strength of 1 to 9
+pub fn generate(rng: &mut Rng, commands: usize) -> String {
+    let mut depth = 0;
+    let mut input = String::new();
+    for _ in 0..commands {
+        let strength = rng.range(1..10);
+        let command = match rng.range(0..3) {
+            0 => "forward",
+            1 => {
+                depth += strength;
+                "down"
+            }
+            _ if depth >= strength => {
+                depth -= strength;
+                "up"
+            }
+            _ => "forward",
+        };
+        input.push_str(&format!("{} {}\n", command, strength));
+    }
+
+    input
+}
+
+/// A scale of 1 is about the size of the real course.  Part two overflows an i32 well before a
+/// scale of 100
+pub fn generate_scaled(rng: &mut Rng, scale: usize) -> String {
+    generate(rng, 1000 * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::generate;
+    use crate::{parse_str, part_one, Command};
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_generated_input() {
+        let mut rng = Rng::new(2);
+        let commands = parse_str(&generate(&mut rng, 300)).unwrap();
+        assert_eq!(commands.len(), 300);
+
+        let (horizontal, depth) = commands
+            .iter()
+            .fold((0, 0), |(horizontal, depth), command| match command {
+                Command::Forward(strength) => (horizontal + strength, depth),
+                Command::Down(strength) => (horizontal, depth + strength),
+                Command::Up(strength) => (horizontal, depth - strength),
+            });
+        assert!(depth >= 0);
+        assert_eq!(part_one(commands).unwrap(), horizontal * depth);
+    }
+}
@@ -1,10 +1,35 @@
+pub mod generator;
+
 use anyhow::{anyhow, Result};
+use aoc_common::{ParseError, ParseResult, Solution};
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::path::Path;
 
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    const DAY: u8 = 2;
+    type Input = Vec<Command>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        parse_str(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        part_one(input.iter().cloned())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        part_two(input.iter().cloned())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Command {
     Forward(i32),
     Down(i32),
@@ -28,34 +53,60 @@ impl TryFrom<(&str, i32)> for Command {
     }
 }
 
-pub fn parse_input<P>(input_path: P) -> Result<Vec<Command>>
+pub fn parse_input<P>(input_path: P) -> ParseResult<Vec<Command>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(input_path)?;
-    let reader = BufReader::new(file);
 
-    reader
-        .lines()
-        .flatten()
-        .map(|input_line| {
+    parse_reader(BufReader::new(file))
+}
+
+pub fn parse_reader<R>(reader: R) -> ParseResult<Vec<Command>>
+where
+    R: BufRead,
+{
+    parse_lines(aoc_common::read_lines(reader)?)
+}
+
+pub fn parse_str(input: &str) -> ParseResult<Vec<Command>> {
+    parse_lines(input.lines())
+}
+
+fn parse_lines<I>(lines: I) -> ParseResult<Vec<Command>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_index, input_line)| {
+            let input_line = input_line.as_ref();
             let mut input_line_split = input_line.trim().split(' ');
             let command_str = input_line_split.next();
             let command_strength = input_line_split.next();
 
-            match (command_str, command_strength) {
-                (Some(command_str), Some(command_strength)) => {
-                    match command_strength.parse::<i32>() {
-                        Ok(command_strength) => Command::try_from((command_str, command_strength)),
-                        Err(_) => Err(anyhow!(
-                            "command_strength \"{:?}\" was not a valid i32",
-                            command_strength
-                        )),
-                    }
+            match (command_str, command_strength, input_line_split.next()) {
+                (Some(command_str), Some(command_strength), None) => {
+                    let strength_column = command_str.len() + 1;
+                    let command_strength = command_strength.parse::<i32>().map_err(|_| {
+                        ParseError::invalid(
+                            line_index,
+                            strength_column,
+                            command_strength,
+                            "an i32 strength",
+                        )
+                    })?;
+                    Command::try_from((command_str, command_strength)).map_err(|_| {
+                        ParseError::invalid(line_index, 0, command_str, "forward, down or up")
+                    })
                 }
-                _ => Err(anyhow!(
-                    "Input line \"{:?}\" contained a number of commands not equal to two",
-                    input_line
+                _ => Err(ParseError::invalid(
+                    line_index,
+                    0,
+                    input_line,
+                    "a command and a strength separated by a space",
                 )),
             }
         })
@@ -112,7 +163,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_input, part_one, part_two, Command};
+    use crate::{part_one, part_two, Command};
 
     #[test]
     fn test_part_one_no_commands() {
@@ -174,14 +225,6 @@ mod tests {
         assert_eq!(result.unwrap(), 150);
     }
 
-    #[test]
-    fn test_part_one_my_input() {
-        let my_input_parsed = parse_input("src/input.txt").unwrap();
-        let result = part_one(my_input_parsed);
-
-        assert_eq!(result.unwrap(), 2091984);
-    }
-
     #[test]
     fn test_part_two_down_forward() {
         let result = part_two([Command::Down(5), Command::Forward(5)]);
@@ -207,12 +250,4 @@ mod tests {
 
         assert_eq!(result.unwrap(), 900);
     }
-
-    #[test]
-    fn test_part_two_my_input() {
-        let my_input_parsed = parse_input("src/input.txt").unwrap();
-        let result = part_two(my_input_parsed);
-
-        assert_eq!(result.unwrap(), 2086261056);
-    }
 }